@@ -1,9 +1,12 @@
-use std::any::TypeId;
-use std::collections::HashSet;
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::any::{Any, TypeId};
 
 use typed_ids::SerialU64;
 
-use super::Interner;
+use super::{InternErr, Interner, TableStats};
+use crate::cell_store::{to_id, CellStore};
 
 /// An [ArrayInterner] uses a [Vec] to intern [Symbol]s.
 /// It performs `intern` in O(n), and `resolve` in O(1).
@@ -11,100 +14,216 @@ use super::Interner;
 /// is stored exactly once in the table is stored without compression.
 #[derive(Default, Clone, Debug)]
 pub struct ArrayInterner {
-    store: Vec<SymbolCell>,
+    // Slot[0] is a sentinel which holds no value, so id 0 never resolves.
+    // An interned empty string gets a slot of its own like any other.
+    store: CellStore<String>,
 }
 
 impl ArrayInterner {
     pub fn new() -> Self {
-        let store = vec![SymbolCell::new(String::from(""))];
-        Self { store }
+        Self {
+            store: CellStore::new(),
+        }
     }
 
-    // returns the position of this string in the table,
-    // offset by the empty block at position 0.
-    fn position(&self, val: &String) -> Option<usize> {
-        self.store
-            .iter()
-            .skip(1)
-            .position(|cell| cell.value() == val)
-            .map(|x| x + 1) // adjust position by one to account for the skip.
+    /// [from_entries] rebuilds an interner from entries sorted by id, as
+    /// produced by [Interner::entries]. Any gaps between ids are filled with
+    /// tombstones, so every entry keeps its original id.
+    #[cfg(feature = "std")]
+    pub(crate) fn from_entries(entries: Vec<(SerialU64<()>, String, Vec<TypeId>)>) -> Self {
+        let mut interner = Self::new();
+        for (id, value, typs) in entries {
+            interner.store.restore(id.get() as usize, value, typs);
+        }
+        interner
+    }
+
+    // Returns the position of this string in the store, which is its id.
+    fn position(&self, val: &str) -> Option<usize> {
+        self.store.position(|value| value == val)
     }
+}
 
-    fn upsert_type(&mut self, position: usize, typ: TypeId) -> SerialU64<()> {
-        let cell = self.store.get_mut(position).unwrap();
-        if !cell.has_type(&typ) {
-            cell.add_type(typ);
+impl Interner for ArrayInterner {
+    fn intern(&mut self, val: String, typ: TypeId) -> Result<SerialU64<()>, InternErr> {
+        // • To intern a string, we scan the vec to see if something matches.
+        match self.position(&val) {
+            // • If we find a match, add the TypeId to it, if it
+            //   isn't already contained within.
+            Some(position) => {
+                self.store.add_type(position, typ);
+                Ok(to_id(position))
+            }
+            // If not found, append a new element to the end of the array.
+            None => self.store.push(val, typ).map(to_id),
         }
-        SerialU64::try_from(position as u64).unwrap()
     }
 
-    fn get_type(&self, position: usize, typ: TypeId) -> Option<SerialU64<()>> {
+    fn intern_reserve(
+        &mut self,
+        val: String,
+        typ: TypeId,
+        extra: usize,
+    ) -> Result<SerialU64<()>, InternErr> {
+        // Each string is stored on its own, so the hint can only pre-size the
+        // cells. Guess the strings to come are about as long as this one.
+        self.store.reserve(1 + extra / val.len().max(1));
+        self.intern(val, typ)
+    }
+
+    fn resolve(&self, id: SerialU64<()>) -> Option<String> {
+        self.store.get(id.get() as usize).cloned()
+    }
+
+    fn resolve_ref(&self, id: SerialU64<()>) -> Option<Cow<'_, str>> {
+        let value = self.store.get(id.get() as usize)?;
+        Some(Cow::Borrowed(value.as_str()))
+    }
+
+    fn get_interned(&self, val: String, typ: TypeId) -> Option<SerialU64<()>> {
+        self.get_interned_str(&val, typ)
+    }
+
+    fn get_interned_str(&self, val: &str, typ: TypeId) -> Option<SerialU64<()>> {
+        // We perform the same steps as intern, except we don't add the
+        // string to the store, instead we check if the TypeId is already
+        // contained within.
+        self.position(val)
+            .filter(|position| self.store.has_type(*position, &typ))
+            .map(to_id)
+    }
+
+    fn get_interned_any(&self, val: &str) -> Option<SerialU64<()>> {
+        self.position(val).map(to_id)
+    }
+
+    fn entries(&self) -> Vec<(SerialU64<()>, String, Vec<TypeId>)> {
+        self.store.entries(String::clone)
+    }
+
+    fn types_for(&self, val: &str) -> Vec<TypeId> {
+        self.position(val)
+            .map(|position| self.store.types(position))
+            .unwrap_or_default()
+    }
+
+    fn strings(&self) -> Vec<String> {
         self.store
-            .get(position)
-            .map(|cell| cell.has_type(&typ))
-            .and_then(|_| SerialU64::try_from(position as u64).ok())
+            .iter()
+            .map(|(_, value, _)| value.clone())
+            .collect()
     }
 
-    fn add_new(&mut self, val: String, typ: TypeId) -> SerialU64<()> {
-        let end = self.store.len();
-        let mut cell = SymbolCell::new(val);
-        cell.add_type(typ);
-        self.store.push(cell);
-        SerialU64::try_from(end as u64).unwrap()
+    fn remove(&mut self, id: SerialU64<()>, typ: TypeId) -> bool {
+        self.store.remove(id.get() as usize, &typ)
     }
-}
 
-#[derive(Clone, Debug)]
-struct SymbolCell {
-    value: String,
-    typs:  HashSet<TypeId>,
-}
+    fn clear(&mut self) {
+        self.store.clear();
+    }
 
-impl SymbolCell {
-    pub fn new(value: String) -> Self {
-        // Fill Slot[0] with an empty cell.
-        Self {
-            value,
-            typs: Default::default(),
-        }
+    fn take_strings(&mut self) -> Vec<String> {
+        // Move the values out of every cell but the sentinel.
+        self.store.take_values()
     }
 
-    fn value(&self) -> &String {
-        &self.value
+    fn reserve(&mut self, additional: usize) {
+        self.store.reserve(additional);
     }
 
-    fn add_type(&mut self, id: TypeId) {
-        self.typs.insert(id);
+    fn shrink_to_fit(&mut self) {
+        self.store.shrink_to_fit();
     }
 
-    fn has_type(&self, id: &TypeId) -> bool {
-        self.typs.contains(id)
+    fn next_id(&self) -> Option<u64> {
+        Some(self.store.next_id())
     }
-}
 
-impl Interner for ArrayInterner {
-    fn intern(&mut self, val: String, typ: TypeId) -> SerialU64<()> {
-        // • To intern a string, we scan the vec to see if something matches.
-        let index = self.position(&val);
-        match index {
-            // • If we find a match, check if the TypeId is already
-            //   contained within. Otherwise, add it.
-            Some(position) => self.upsert_type(position, typ),
-            // If not found, append a new element to the end of the array.
-            None => self.add_new(val, typ),
+    fn len(&self) -> usize {
+        self.store.len()
+    }
+
+    fn stats(&self) -> TableStats {
+        TableStats {
+            num_entries:           self.len(),
+            total_bytes_stored:    self.store.iter().map(|(_, value, _)| value.len()).sum(),
+            num_type_associations: self.store.num_type_associations(),
+            estimated_heap_bytes:  self.heap_size(),
         }
     }
 
-    fn resolve(&self, id: SerialU64<()>) -> String {
-        let index = id.get() as usize;
-        self.store.get(index).unwrap().value().clone()
+    fn heap_size(&self) -> usize {
+        self.store.heap_size(String::capacity)
     }
 
-    fn get_interned(&self, val: String, typ: TypeId) -> Option<SerialU64<()>> {
-        // We perform the same steps as intern, except we don't add the
-        // string to the store, instead we check if the TypeId is already
-        // contained within.
-        self.position(&val)
-            .and_then(|position| self.get_type(position, typ))
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+
+    use typed_ids::SerialU64;
+
+    use super::ArrayInterner;
+    use crate::Interner;
+
+    #[test]
+    fn reserve_grows_capacity() {
+        let mut interner = ArrayInterner::new();
+        interner.reserve(100);
+        assert!(interner.store.capacity() >= 101);
+        assert_eq!(interner.len(), 0);
+    }
+
+    #[test]
+    fn intern_reserve_grows_capacity() {
+        let typ = TypeId::of::<String>();
+        let mut interner = ArrayInterner::new();
+        let frog = interner
+            .intern_reserve("frog".to_owned(), typ, 400)
+            .unwrap();
+        assert!(interner.store.capacity() >= 101);
+        assert_eq!(interner.resolve(frog).as_deref(), Some("frog"));
+        // • An empty string doesn't say how long the rest will be.
+        let empty = interner.intern_reserve(String::new(), typ, 400).unwrap();
+        assert!(interner.store.capacity() >= 403);
+        assert_eq!(interner.resolve(empty).as_deref(), Some(""));
+    }
+
+    #[test]
+    fn shrink_releases_capacity() {
+        let mut interner = ArrayInterner::new();
+        interner.reserve(100);
+        let reserved = interner.store.capacity();
+        interner.shrink_to_fit();
+        assert!(interner.store.capacity() < reserved);
+    }
+
+    #[test]
+    fn empty_string_is_not_the_sentinel() {
+        let typ = TypeId::of::<String>();
+        let mut interner = ArrayInterner::new();
+        assert_eq!(interner.resolve(SerialU64::try_from(0).unwrap()), None);
+        let empty = interner.intern(String::new(), typ).unwrap();
+        assert_ne!(empty.get(), 0);
+        assert_eq!(interner.resolve(empty).as_deref(), Some(""));
+        assert_eq!(interner.get_interned(String::new(), typ), Some(empty));
+        assert_eq!(interner.len(), 1);
+    }
+
+    #[test]
+    fn heap_size_grows() {
+        let typ = TypeId::of::<String>();
+        let mut interner = ArrayInterner::new();
+        let empty = interner.heap_size();
+        interner.intern("toad".to_string(), typ).unwrap();
+        let one = interner.heap_size();
+        assert!(one > empty);
+        interner.intern("frog".to_string(), typ).unwrap();
+        assert!(interner.heap_size() > one);
+        assert_eq!(interner.stats().estimated_heap_bytes, interner.heap_size());
     }
 }
@@ -0,0 +1,157 @@
+use alloc::vec::Vec;
+use core::any::TypeId;
+
+use typed_ids::SerialU64;
+
+use super::InternErr;
+use crate::cell_store::{to_id, CellStore};
+
+/// A type is [InternableBytes] if it supports conversion to and from a byte
+/// vector, and it is static. It is the binary sibling of [Internable], for
+/// data which isn't valid UTF-8 and so can't be stored in a [SymbolTable].
+pub trait InternableBytes: TryFrom<Vec<u8>> + AsRef<[u8]> {}
+
+/// This Blanket implementation allows any type that implements the type
+/// bounds of [InternableBytes] to implicitly be [InternableBytes].
+impl<T: TryFrom<Vec<u8>> + AsRef<[u8]>> InternableBytes for T {}
+
+/// [Bytes] is an opaque blob of bytes, for interning binary data under a
+/// type of its own rather than as a bare `Vec<u8>`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Bytes(pub Vec<u8>);
+
+impl Bytes {
+    /// [into_vec] unwraps the blob into its bytes.
+    pub fn into_vec(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl AsRef<[u8]> for Bytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<Vec<u8>> for Bytes {
+    fn from(val: Vec<u8>) -> Self {
+        Self(val)
+    }
+}
+
+impl From<&[u8]> for Bytes {
+    fn from(val: &[u8]) -> Self {
+        Self(val.to_vec())
+    }
+}
+
+/// A [BinaryInterner] is the byte counterpart of a [SymbolTable] backed by
+/// an [ArrayInterner]. It interns any [InternableBytes], storing each
+/// distinct byte sequence once, and issues typed ids in place of [Symbol]s.
+/// Like the [TableSnapshot], it doesn't check that an id came from this
+/// interner. It performs `intern` in O(n), and `resolve` in O(1).
+#[derive(Clone, Debug)]
+pub struct BinaryInterner {
+    store: CellStore<Vec<u8>>,
+}
+
+impl BinaryInterner {
+    pub fn new() -> Self {
+        Self {
+            store: CellStore::new(),
+        }
+    }
+
+    /// [intern] stores the item's bytes, if they aren't already stored, and
+    /// returns their id. It fails if the bytes are new, but every id is
+    /// taken.
+    pub fn intern<T: InternableBytes + 'static>(
+        &mut self,
+        item: &T,
+    ) -> Result<SerialU64<T>, InternErr> {
+        let val = item.as_ref();
+        let typ = TypeId::of::<T>();
+        // • If the bytes are already stored, add the type to their cell.
+        if let Some(position) = self.position(val) {
+            self.store.add_type(position, typ);
+            return Ok(to_id(position));
+        }
+        // • Otherwise, append a new cell holding a copy of them.
+        self.store.push(val.to_vec(), typ).map(to_id)
+    }
+
+    /// [resolve] returns the object that was originally stored under the id.
+    /// It returns [None] if the id is unknown, was issued for another type,
+    /// or its bytes can't be converted back into a `T`.
+    pub fn resolve<T: InternableBytes + 'static>(&self, id: SerialU64<T>) -> Option<T> {
+        let position = id.get() as usize;
+        if !self.store.has_type(position, &TypeId::of::<T>()) {
+            return None;
+        }
+        T::try_from(self.store.get(position)?.clone()).ok()
+    }
+
+    pub fn get_interned<T: InternableBytes + 'static>(&self, val: &[u8]) -> Option<SerialU64<T>> {
+        self.position(val)
+            .filter(|position| self.store.has_type(*position, &TypeId::of::<T>()))
+            .map(to_id)
+    }
+
+    pub fn has_interned<T: InternableBytes + 'static>(&self, val: &[u8]) -> bool {
+        self.get_interned::<T>(val).is_some()
+    }
+
+    /// [remove] detaches `T` from the bytes stored under the id, returning
+    /// false if they weren't interned as a `T`. Once no types remain, the
+    /// bytes are released. Ids are never reissued.
+    pub fn remove<T: InternableBytes + 'static>(&mut self, id: SerialU64<T>) -> bool {
+        self.store.remove(id.get() as usize, &TypeId::of::<T>())
+    }
+
+    /// [len] returns the number of distinct byte sequences stored.
+    pub fn len(&self) -> usize {
+        self.store.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn position(&self, val: &[u8]) -> Option<usize> {
+        self.store.position(|value| value == val)
+    }
+}
+
+impl Default for BinaryInterner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BinaryInterner, Bytes};
+
+    #[test]
+    fn non_utf8_round_trip() {
+        let blob = vec![0xff, 0xfe, 0x00, 0x80];
+        assert!(String::from_utf8(blob.clone()).is_err());
+        let mut interner = BinaryInterner::new();
+        let id = interner.intern(&Bytes(blob.clone())).unwrap();
+        assert_eq!(interner.intern(&Bytes(blob.clone())), Ok(id));
+        assert_eq!(
+            interner.resolve(id).map(Bytes::into_vec),
+            Some(blob.clone())
+        );
+        // • A bare Vec<u8> shares the bytes, but not the type.
+        assert!(!interner.has_interned::<Vec<u8>>(&blob));
+        let raw = interner.intern(&blob).unwrap();
+        assert_eq!(raw.get(), id.get());
+        assert_eq!(interner.resolve(raw), Some(blob.clone()));
+        assert_eq!(interner.len(), 1);
+        assert!(interner.remove(id));
+        assert_eq!(interner.resolve(id), None);
+        assert!(interner.remove(raw));
+        assert!(interner.is_empty());
+    }
+}
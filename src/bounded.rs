@@ -0,0 +1,351 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::any::{Any, TypeId};
+use core::mem::size_of;
+
+use typed_ids::SerialU64;
+
+use super::{InternErr, Interner, TableStats};
+use crate::interner::issue_id;
+use crate::stats::type_set_bytes;
+
+/// A [BoundedInterner] holds at most a fixed number of strings. Once it is
+/// full, interning a new string evicts the least recently interned one and
+/// reuses its slot. Evicted ids are never reissued, so a [Symbol] for an
+/// evicted string fails to resolve, rather than resolving to its replacement.
+/// Only interning counts as a use; resolving a Symbol doesn't refresh it.
+/// Like the [ArrayInterner], it performs `intern` in O(n).
+#[derive(Clone, Debug)]
+pub struct BoundedInterner {
+    // The most strings the interner will hold at once.
+    capacity: usize,
+    // Each slot holds a string, or is empty once its string is removed.
+    slots:    Vec<Option<BoundedCell>>,
+    // Maps each live id to the slot holding its string.
+    ids:      BTreeMap<u64, usize>,
+    // Maps the last time each live slot was interned to the slot,
+    // so the first entry is the least recently used.
+    recency:  BTreeMap<u64, usize>,
+    // The next id to issue. Id 0 is never issued.
+    next_id:  u64,
+    // Ticks once for every call to intern.
+    clock:    u64,
+}
+
+#[derive(Clone, Debug)]
+struct BoundedCell {
+    id:        u64,
+    value:     String,
+    typs:      BTreeSet<TypeId>,
+    last_used: u64,
+}
+
+impl BoundedInterner {
+    /// [new] constructs an empty interner holding at most `capacity` strings.
+    ///
+    /// # Panics
+    /// If `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        assert!(
+            capacity > 0,
+            "a BoundedInterner must hold at least one string"
+        );
+        Self {
+            capacity,
+            slots: Vec::new(),
+            ids: BTreeMap::new(),
+            recency: BTreeMap::new(),
+            next_id: 1,
+            clock: 0,
+        }
+    }
+
+    /// [capacity] returns the most strings the interner will hold at once.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    fn position(&self, val: &str) -> Option<usize> {
+        self.slots
+            .iter()
+            .position(|slot| slot.as_ref().is_some_and(|cell| cell.value == val))
+    }
+
+    fn cell(&self, id: SerialU64<()>) -> Option<&BoundedCell> {
+        let slot = *self.ids.get(&id.get())?;
+        self.slots[slot].as_ref()
+    }
+
+    // Marks the slot as the most recently used.
+    fn refresh(&mut self, slot: usize) {
+        self.clock += 1;
+        let cell = self.slots[slot].as_mut().unwrap();
+        self.recency.remove(&cell.last_used);
+        cell.last_used = self.clock;
+        self.recency.insert(self.clock, slot);
+    }
+
+    // Empties the slot, forgetting its id.
+    fn release(&mut self, slot: usize) {
+        if let Some(cell) = self.slots[slot].take() {
+            self.ids.remove(&cell.id);
+            self.recency.remove(&cell.last_used);
+        }
+    }
+
+    // Finds a slot for a new string: an empty slot if there is one, a new
+    // slot while under capacity, and otherwise the least recently used slot.
+    fn vacant_slot(&mut self) -> usize {
+        if let Some(slot) = self.slots.iter().position(Option::is_none) {
+            return slot;
+        }
+        if self.slots.len() < self.capacity {
+            self.slots.push(None);
+            return self.slots.len() - 1;
+        }
+        let (_, slot) = self.recency.pop_first().unwrap();
+        self.release(slot);
+        slot
+    }
+
+    fn to_id(&self, id: u64) -> SerialU64<()> {
+        SerialU64::try_from(id).unwrap()
+    }
+}
+
+impl Interner for BoundedInterner {
+    fn intern(&mut self, val: String, typ: TypeId) -> Result<SerialU64<()>, InternErr> {
+        // • If the string is already held, add the type and refresh it.
+        if let Some(slot) = self.position(&val) {
+            let cell = self.slots[slot].as_mut().unwrap();
+            cell.typs.insert(typ);
+            let id = cell.id;
+            self.refresh(slot);
+            return Ok(self.to_id(id));
+        }
+        // • Otherwise, check a fresh id is left before evicting anything,
+        //   then find room for the string and issue it the id.
+        let issued = issue_id(self.next_id)?;
+        let slot = self.vacant_slot();
+        let id = self.next_id;
+        self.next_id += 1;
+        let mut typs = BTreeSet::new();
+        typs.insert(typ);
+        self.slots[slot] = Some(BoundedCell {
+            id,
+            value: val,
+            typs,
+            last_used: 0,
+        });
+        self.ids.insert(id, slot);
+        self.refresh(slot);
+        Ok(issued)
+    }
+
+    // Evicting keeps the length steady, so newness can't be
+    // judged by the length alone.
+    fn intern_with_status(
+        &mut self,
+        val: String,
+        typ: TypeId,
+    ) -> Result<(SerialU64<()>, bool), InternErr> {
+        let is_new = self.position(&val).is_none();
+        Ok((self.intern(val, typ)?, is_new))
+    }
+
+    fn resolve(&self, id: SerialU64<()>) -> Option<String> {
+        self.cell(id).map(|cell| cell.value.clone())
+    }
+
+    fn get_interned(&self, val: String, typ: TypeId) -> Option<SerialU64<()>> {
+        self.get_interned_str(&val, typ)
+    }
+
+    fn get_interned_str(&self, val: &str, typ: TypeId) -> Option<SerialU64<()>> {
+        let cell = self.slots[self.position(val)?].as_ref()?;
+        cell.typs.contains(&typ).then(|| self.to_id(cell.id))
+    }
+
+    fn get_interned_any(&self, val: &str) -> Option<SerialU64<()>> {
+        let cell = self.slots[self.position(val)?].as_ref()?;
+        Some(self.to_id(cell.id))
+    }
+
+    fn entries(&self) -> Vec<(SerialU64<()>, String, Vec<TypeId>)> {
+        self.ids
+            .iter()
+            .filter_map(|(id, slot)| {
+                let cell = self.slots[*slot].as_ref()?;
+                let typs = cell.typs.iter().cloned().collect();
+                Some((self.to_id(*id), cell.value.clone(), typs))
+            })
+            .collect()
+    }
+
+    fn remove(&mut self, id: SerialU64<()>, typ: TypeId) -> bool {
+        let slot = match self.ids.get(&id.get()) {
+            Some(slot) => *slot,
+            None => return false,
+        };
+        let cell = self.slots[slot].as_mut().unwrap();
+        if !cell.typs.remove(&typ) {
+            return false;
+        }
+        // • Once no types refer to the string, free its slot.
+        if cell.typs.is_empty() {
+            self.release(slot);
+        }
+        true
+    }
+
+    fn clear(&mut self) {
+        *self = Self::new(self.capacity);
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        // There's never any need for more than `capacity` slots.
+        let room = self.capacity - self.slots.len();
+        self.slots.reserve(additional.min(room));
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.slots.shrink_to_fit();
+    }
+
+    fn evicts(&self) -> bool {
+        true
+    }
+
+    fn touch(&mut self, id: SerialU64<()>) {
+        if let Some(slot) = self.ids.get(&id.get()).copied() {
+            self.refresh(slot);
+        }
+    }
+
+    fn next_id(&self) -> Option<u64> {
+        Some(self.next_id)
+    }
+
+    fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    fn stats(&self) -> TableStats {
+        let cells = || self.slots.iter().flatten();
+        TableStats {
+            num_entries:           self.len(),
+            total_bytes_stored:    cells().map(|cell| cell.value.len()).sum(),
+            num_type_associations: cells().map(|cell| cell.typs.len()).sum(),
+            estimated_heap_bytes:  self.heap_size(),
+        }
+    }
+
+    fn heap_size(&self) -> usize {
+        let slots = self.slots.capacity() * size_of::<Option<BoundedCell>>();
+        let cells: usize = self
+            .slots
+            .iter()
+            .flatten()
+            .map(|cell| cell.value.capacity() + type_set_bytes(&cell.typs))
+            .sum();
+        // Both maps hold one (u64, usize) pair per live string.
+        let maps = (self.ids.len() + self.recency.len()) * size_of::<(u64, usize)>();
+        slots + cells + maps
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+
+    use super::BoundedInterner;
+    use crate::{Interner, InternerFlavor, ResolutionErr, Symbol, SymbolTable};
+
+    fn intern(interner: &mut BoundedInterner, val: &str) -> u64 {
+        interner
+            .intern(val.to_string(), TypeId::of::<String>())
+            .unwrap()
+            .get()
+    }
+
+    fn strings(interner: &BoundedInterner) -> Vec<String> {
+        interner.strings()
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let mut interner = BoundedInterner::new(3);
+        let toad = intern(&mut interner, "toad");
+        intern(&mut interner, "frog");
+        intern(&mut interner, "newt");
+        // Interning toad again makes frog the least recently used.
+        assert_eq!(intern(&mut interner, "toad"), toad);
+        intern(&mut interner, "eft");
+        assert_eq!(strings(&interner), ["toad", "newt", "eft"]);
+        intern(&mut interner, "axolotl");
+        assert_eq!(strings(&interner), ["toad", "eft", "axolotl"]);
+        intern(&mut interner, "olm");
+        assert_eq!(strings(&interner), ["eft", "axolotl", "olm"]);
+        assert_eq!(interner.len(), 3);
+        assert_eq!(interner.slots.len(), 3);
+    }
+
+    #[test]
+    fn evicted_ids_are_not_reused() {
+        let mut interner = BoundedInterner::new(1);
+        let toad = intern(&mut interner, "toad");
+        let frog = intern(&mut interner, "frog");
+        assert_ne!(toad, frog);
+        let again = intern(&mut interner, "toad");
+        assert_ne!(again, toad);
+        assert_eq!(
+            interner.resolve(again.try_into().unwrap()).as_deref(),
+            Some("toad")
+        );
+        assert_eq!(interner.resolve(frog.try_into().unwrap()), None);
+    }
+
+    #[test]
+    fn removal_frees_a_slot() {
+        let mut interner = BoundedInterner::new(2);
+        let typ = TypeId::of::<String>();
+        let toad = interner.intern("toad".to_string(), typ).unwrap();
+        intern(&mut interner, "frog");
+        assert!(interner.remove(toad, typ));
+        // The freed slot is used before anything is evicted.
+        intern(&mut interner, "newt");
+        assert_eq!(strings(&interner), ["frog", "newt"]);
+    }
+
+    #[test]
+    fn evicted_symbols_fail_to_resolve() {
+        let mut table = SymbolTable::new(InternerFlavor::Bounded(2));
+        let toad: Symbol<String> = table.intern(&"toad".to_owned());
+        let frog: Symbol<String> = table.intern(&"frog".to_owned());
+        let newt: Symbol<String> = table.intern(&"newt".to_owned());
+        assert_eq!(table.resolve(&toad), Err(ResolutionErr::UnknownIdErr));
+        assert_eq!(table.resolve(&frog), Ok("frog".to_owned()));
+        assert_eq!(table.resolve(&newt), Ok("newt".to_owned()));
+        assert!(!table.has_interned::<String, _>("toad"));
+        assert_eq!(
+            toad.to_string(),
+            format!("<unresolvable symbol #{}>", toad.raw())
+        );
+    }
+
+    #[test]
+    fn status_after_eviction() {
+        let mut table = SymbolTable::new(InternerFlavor::Bounded(1));
+        let (_, is_new) = table.intern_with_status(&"toad".to_owned());
+        assert!(is_new);
+        let (_, is_new) = table.intern_with_status(&"frog".to_owned());
+        assert!(is_new);
+        let (_, is_new) = table.intern_with_status(&"frog".to_owned());
+        assert!(!is_new);
+    }
+}
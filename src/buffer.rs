@@ -0,0 +1,180 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::any::{Any, TypeId};
+use core::str;
+
+use typed_ids::SerialU64;
+
+use super::{InternErr, Interner, TableStats};
+use crate::cell_store::{to_id, CellStore};
+
+/// A [BufferInterner] stores every interned string back to back in a single
+/// growing buffer, recording where each one starts and how long it is. This
+/// avoids a separate heap allocation per string, which dominates the memory
+/// use of many small strings. Like the [ArrayInterner], it performs `intern`
+/// in O(n), and `resolve` in O(1). Removing a string doesn't reclaim its
+/// bytes, since that would move the strings after it.
+#[derive(Clone, Debug)]
+pub struct BufferInterner {
+    // The bytes of every interned string, concatenated.
+    buffer: Vec<u8>,
+    // Where each string lies in the buffer, by id.
+    spans:  CellStore<Span>,
+}
+
+#[derive(Clone, Debug)]
+struct Span {
+    offset: usize,
+    len:    usize,
+}
+
+impl BufferInterner {
+    pub fn new() -> Self {
+        Self {
+            buffer: Vec::new(),
+            spans:  CellStore::new(),
+        }
+    }
+
+    fn value(&self, span: &Span) -> &str {
+        let bytes = &self.buffer[span.offset..span.offset + span.len];
+        // Only whole strings are ever appended to the buffer.
+        str::from_utf8(bytes).expect("spans always cover valid UTF-8")
+    }
+
+    fn position(&self, val: &str) -> Option<usize> {
+        self.spans.position(|span| self.value(span) == val)
+    }
+}
+
+impl Default for BufferInterner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Interner for BufferInterner {
+    fn intern(&mut self, val: String, typ: TypeId) -> Result<SerialU64<()>, InternErr> {
+        // • If the string is already stored, add the type to its span.
+        if let Some(position) = self.position(&val) {
+            self.spans.add_type(position, typ);
+            return Ok(to_id(position));
+        }
+        // • Otherwise, record where its bytes will go, then append them
+        //   to the buffer.
+        let span = Span {
+            offset: self.buffer.len(),
+            len:    val.len(),
+        };
+        let position = self.spans.push(span, typ)?;
+        self.buffer.extend_from_slice(val.as_bytes());
+        Ok(to_id(position))
+    }
+
+    fn intern_reserve(
+        &mut self,
+        val: String,
+        typ: TypeId,
+        extra: usize,
+    ) -> Result<SerialU64<()>, InternErr> {
+        // Make room for this string and the ones to come in a single step.
+        self.buffer.reserve(val.len() + extra);
+        self.intern(val, typ)
+    }
+
+    fn resolve(&self, id: SerialU64<()>) -> Option<String> {
+        self.resolve_ref(id).map(Cow::into_owned)
+    }
+
+    fn resolve_ref(&self, id: SerialU64<()>) -> Option<Cow<'_, str>> {
+        let span = self.spans.get(id.get() as usize)?;
+        Some(Cow::Borrowed(self.value(span)))
+    }
+
+    fn get_interned(&self, val: String, typ: TypeId) -> Option<SerialU64<()>> {
+        self.get_interned_str(&val, typ)
+    }
+
+    fn get_interned_str(&self, val: &str, typ: TypeId) -> Option<SerialU64<()>> {
+        self.position(val)
+            .filter(|position| self.spans.has_type(*position, &typ))
+            .map(to_id)
+    }
+
+    fn get_interned_any(&self, val: &str) -> Option<SerialU64<()>> {
+        self.position(val).map(to_id)
+    }
+
+    fn entries(&self) -> Vec<(SerialU64<()>, String, Vec<TypeId>)> {
+        self.spans.entries(|span| self.value(span).into())
+    }
+
+    fn remove(&mut self, id: SerialU64<()>, typ: TypeId) -> bool {
+        // Once no types refer to the string, its span is forgotten. The
+        // bytes stay in the buffer, but are never resolved again.
+        self.spans.remove(id.get() as usize, &typ)
+    }
+
+    fn clear(&mut self) {
+        self.buffer.clear();
+        self.spans.clear();
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.spans.reserve(additional);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.buffer.shrink_to_fit();
+        self.spans.shrink_to_fit();
+    }
+
+    fn next_id(&self) -> Option<u64> {
+        Some(self.spans.next_id())
+    }
+
+    fn len(&self) -> usize {
+        self.spans.len()
+    }
+
+    fn stats(&self) -> TableStats {
+        TableStats {
+            num_entries:           self.len(),
+            total_bytes_stored:    self.spans.iter().map(|(_, span, _)| span.len).sum(),
+            num_type_associations: self.spans.num_type_associations(),
+            estimated_heap_bytes:  self.heap_size(),
+        }
+    }
+
+    fn heap_size(&self) -> usize {
+        // The spans point into the buffer, so they hold nothing of their own.
+        self.buffer.capacity() + self.spans.heap_size(|_| 0)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{InternerFlavor, Symbol, SymbolTable};
+
+    #[test]
+    fn round_trip() {
+        let mut table = SymbolTable::new(InternerFlavor::Buffer);
+        let toad: Symbol<String> = table.intern(&"toad".to_owned());
+        let cafe: Symbol<String> = table.intern(&"café".to_owned());
+        let empty: Symbol<String> = table.intern(&String::new());
+        assert_eq!(table.resolve(&toad), Ok("toad".to_owned()));
+        assert_eq!(table.resolve(&cafe), Ok("café".to_owned()));
+        assert_eq!(table.resolve(&empty), Ok(String::new()));
+        assert_eq!(table.intern(&"café".to_owned()), cafe);
+        assert_eq!(table.with_resolved(&cafe, str::len), Ok(5));
+        assert!(table.remove(&cafe));
+        assert!(table.resolve(&cafe).is_err());
+        assert_eq!(table.resolve(&toad), Ok("toad".to_owned()));
+        assert_eq!(table.len(), 2);
+    }
+}
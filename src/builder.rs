@@ -0,0 +1,84 @@
+use super::{CaseInsensitiveInterner, InternerFlavor, SymbolTable};
+
+/// A [SymbolTableBuilder] configures a [SymbolTable] before it's built.
+/// Options which aren't set fall back to their defaults: an
+/// [InternerFlavor::Array] table, with no reserved capacity, which
+/// distinguishes strings by case.
+pub struct SymbolTableBuilder {
+    flavor:           InternerFlavor,
+    capacity:         usize,
+    case_insensitive: bool,
+}
+
+impl SymbolTableBuilder {
+    pub fn new() -> Self {
+        Self {
+            flavor:           InternerFlavor::Array,
+            capacity:         0,
+            case_insensitive: false,
+        }
+    }
+
+    /// [flavor] picks the data structure backing the table.
+    pub fn flavor(mut self, flavor: InternerFlavor) -> Self {
+        self.flavor = flavor;
+        self
+    }
+
+    /// [capacity] reserves room for at least this many strings up front.
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// [case_insensitive] wraps the table's interner in a
+    /// [CaseInsensitiveInterner] when set.
+    pub fn case_insensitive(mut self, case_insensitive: bool) -> Self {
+        self.case_insensitive = case_insensitive;
+        self
+    }
+
+    pub fn build(self) -> SymbolTable {
+        let mut table = if self.case_insensitive {
+            SymbolTable::from(CaseInsensitiveInterner::new(self.flavor))
+        } else {
+            SymbolTable::new(self.flavor)
+        };
+        table.reserve(self.capacity);
+        table
+    }
+}
+
+impl Default for SymbolTableBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SymbolTableBuilder;
+    use crate::{InternerFlavor, Symbol, SymbolTable};
+
+    #[test]
+    fn array_with_capacity() {
+        let mut table = SymbolTableBuilder::new()
+            .flavor(InternerFlavor::Array)
+            .capacity(64)
+            .build();
+        assert!(table.is_empty());
+        let toad: Symbol<String> = table.intern(&"toad".to_owned());
+        assert_eq!(table.resolve(&toad), Ok("toad".to_owned()));
+        assert_eq!(table.len(), 1);
+    }
+
+    #[test]
+    fn case_insensitive() {
+        let mut table = SymbolTable::builder()
+            .flavor(InternerFlavor::Radix)
+            .case_insensitive(true)
+            .build();
+        let alice: Symbol<String> = table.intern(&"Alice".to_owned());
+        assert_eq!(table.get_interned::<String, _>("ALICE"), Some(alice));
+    }
+}
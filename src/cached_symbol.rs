@@ -0,0 +1,70 @@
+use alloc::string::String;
+use core::fmt;
+
+use typed_ids::SerialU64;
+
+use crate::Internable;
+
+/// A [CachedSymbol] holds the string behind a [Symbol], returned by
+/// [Symbol::cache]. The string is resolved once, when the cache is created,
+/// so formatting it again and again never goes back to the table. It is
+/// a snapshot: removing the string from the table afterwards doesn't
+/// change what the cache holds.
+pub struct CachedSymbol<T: Internable + 'static> {
+    id:    SerialU64<T>,
+    value: String,
+}
+
+impl<T: Internable + 'static> CachedSymbol<T> {
+    pub(crate) fn new(id: SerialU64<T>, value: String) -> Self {
+        Self { id, value }
+    }
+
+    /// [as_str] returns the cached string, which matches the Symbol's
+    /// [Display] output when the cache was created.
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    /// [raw] returns the id of the Symbol this cache was created from.
+    pub fn raw(&self) -> u64 {
+        self.id.get()
+    }
+}
+
+impl<T: Internable + 'static> AsRef<str> for CachedSymbol<T> {
+    fn as_ref(&self) -> &str {
+        &self.value
+    }
+}
+
+impl<T: Internable + 'static> fmt::Display for CachedSymbol<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl<T: Internable + 'static> fmt::Debug for CachedSymbol<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{InternerFlavor, Symbol, SymbolTable};
+
+    #[test]
+    fn matches_to_string() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let toad: Symbol<String> = table.intern(&"toad".to_owned());
+        let cached = toad.cache();
+        assert_eq!(cached.as_str(), toad.to_string());
+        assert_eq!(cached.raw(), toad.raw());
+        // • The cache outlives the string's removal from the table.
+        let expected = toad.to_string();
+        table.remove(&toad);
+        assert_eq!(cached.as_str(), expected);
+        assert_eq!(toad.cache().as_str(), toad.to_string());
+    }
+}
@@ -0,0 +1,211 @@
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::any::{Any, TypeId};
+use core::mem::size_of;
+
+use typed_ids::SerialU64;
+
+use super::{InternErr, Interner, InternerFlavor, TableStats};
+
+/// A [CaseInsensitiveInterner] wraps another [Interner], treating strings
+/// which differ only by case as the same string. The first casing seen for
+/// a string is the one that is stored, and every later spelling resolves
+/// to it. Build a [SymbolTable] from one with [SymbolTable::from].
+///
+/// Case is folded without regard to locale, so the Turkish dotted `İ`
+/// matches `i̇` (an `i` with a combining dot), but not a plain `i`.
+pub struct CaseInsensitiveInterner {
+    inner:  Box<dyn Interner + Send + Sync>,
+    // Maps the case-folded form of each string to its id in `inner`.
+    folded: BTreeMap<String, SerialU64<()>>,
+}
+
+impl CaseInsensitiveInterner {
+    pub fn new(flavor: InternerFlavor) -> Self {
+        Self {
+            inner:  flavor.interner(),
+            folded: BTreeMap::new(),
+        }
+    }
+}
+
+// Approximates Unicode full case folding: uppercasing first expands
+// characters like `ß` into `SS`, which then lowercase to `ss`.
+fn fold(val: &str) -> String {
+    val.to_uppercase().to_lowercase()
+}
+
+impl Interner for CaseInsensitiveInterner {
+    fn intern(&mut self, val: String, typ: TypeId) -> Result<SerialU64<()>, InternErr> {
+        let key = fold(&val);
+        // • If some casing of this string was already interned,
+        //   re-intern that original casing to attach the type.
+        let original = self.folded.get(&key).and_then(|id| self.inner.resolve(*id));
+        match original {
+            Some(original) => self.inner.intern(original, typ),
+            None => {
+                let id = self.inner.intern(val, typ)?;
+                self.folded.insert(key, id);
+                Ok(id)
+            }
+        }
+    }
+
+    fn resolve(&self, id: SerialU64<()>) -> Option<String> {
+        self.inner.resolve(id)
+    }
+
+    fn resolve_ref(&self, id: SerialU64<()>) -> Option<Cow<'_, str>> {
+        self.inner.resolve_ref(id)
+    }
+
+    fn get_interned(&self, val: String, typ: TypeId) -> Option<SerialU64<()>> {
+        self.get_interned_str(&val, typ)
+    }
+
+    fn get_interned_str(&self, val: &str, typ: TypeId) -> Option<SerialU64<()>> {
+        // Folding the string allocates, but the original casing is borrowed.
+        let id = self.folded.get(&fold(val))?;
+        let original = self.inner.resolve_ref(*id)?;
+        self.inner.get_interned_str(&original, typ)
+    }
+
+    fn entries(&self) -> Vec<(SerialU64<()>, String, Vec<TypeId>)> {
+        self.inner.entries()
+    }
+
+    fn get_interned_any(&self, val: &str) -> Option<SerialU64<()>> {
+        // The folded map may still hold the id of a removed string.
+        let id = *self.folded.get(&fold(val))?;
+        self.inner.resolve(id).map(|_| id)
+    }
+
+    fn types_for(&self, val: &str) -> Vec<TypeId> {
+        self.folded
+            .get(&fold(val))
+            .and_then(|id| self.inner.resolve(*id))
+            .map(|original| self.inner.types_for(&original))
+            .unwrap_or_default()
+    }
+
+    fn strings(&self) -> Vec<String> {
+        self.inner.strings()
+    }
+
+    fn remove(&mut self, id: SerialU64<()>, typ: TypeId) -> bool {
+        let original = match self.inner.resolve(id) {
+            Some(original) => original,
+            None => return false,
+        };
+        if !self.inner.remove(id, typ) {
+            return false;
+        }
+        // • If the inner interner released the string, forget its folding
+        //   so a later spelling can be stored fresh.
+        if self.inner.resolve(id).is_none() {
+            self.folded.remove(&fold(&original));
+        }
+        true
+    }
+
+    fn clear(&mut self) {
+        self.inner.clear();
+        self.folded.clear();
+    }
+
+    // The folded map is a BTreeMap, which allocates node by node,
+    // so only the inner interner has capacity to manage.
+    fn reserve(&mut self, additional: usize) {
+        self.inner.reserve(additional);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.inner.shrink_to_fit();
+    }
+
+    fn touch(&mut self, id: SerialU64<()>) {
+        self.inner.touch(id);
+    }
+
+    fn evicts(&self) -> bool {
+        self.inner.evicts()
+    }
+
+    fn next_id(&self) -> Option<u64> {
+        self.inner.next_id()
+    }
+
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn stats(&self) -> TableStats {
+        TableStats {
+            estimated_heap_bytes: self.heap_size(),
+            ..self.inner.stats()
+        }
+    }
+
+    fn heap_size(&self) -> usize {
+        // Each folded key is a String of its own, on top of the inner store.
+        let folded: usize = self
+            .folded
+            .keys()
+            .map(|key| key.capacity() + size_of::<(String, SerialU64<()>)>())
+            .sum();
+        self.inner.heap_size() + folded
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CaseInsensitiveInterner;
+    use crate::{InternerFlavor, Symbol, SymbolTable};
+
+    fn table() -> SymbolTable {
+        SymbolTable::from(CaseInsensitiveInterner::new(InternerFlavor::Array))
+    }
+
+    #[test]
+    fn keeps_first_casing() {
+        let mut table = table();
+        let alice: Symbol<String> = table.intern(&"Alice".to_owned());
+        let lower: Symbol<String> = table.intern(&"alice".to_owned());
+        assert_eq!(alice, lower);
+        assert_eq!(table.resolve(&lower), Ok("Alice".to_owned()));
+        assert_eq!(table.get_interned::<String, _>("ALICE"), Some(alice));
+        assert_eq!(table.len(), 1);
+    }
+
+    #[test]
+    fn unicode_folding() {
+        let mut table = table();
+        let strasse: Symbol<String> = table.intern(&"Straße".to_owned());
+        assert_eq!(table.get_interned::<String, _>("STRASSE"), Some(strasse));
+
+        // Dotted capital I folds to an i with a combining dot above,
+        // which is distinct from a plain i.
+        let dotted: Symbol<String> = table.intern(&"İ".to_owned());
+        let plain: Symbol<String> = table.intern(&"i".to_owned());
+        assert_ne!(dotted, plain);
+        assert_eq!(table.get_interned::<String, _>("i\u{307}"), Some(dotted));
+        assert_eq!(table.get_interned::<String, _>("I"), Some(plain));
+    }
+
+    #[test]
+    fn removal_forgets_folding() {
+        let mut table = table();
+        let alice: Symbol<String> = table.intern(&"Alice".to_owned());
+        assert!(table.remove(&alice));
+        assert!(!table.has_interned::<String, _>("alice"));
+        let lower: Symbol<String> = table.intern(&"alice".to_owned());
+        assert_eq!(table.resolve(&lower), Ok("alice".to_owned()));
+    }
+}
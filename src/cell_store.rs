@@ -0,0 +1,233 @@
+use alloc::collections::BTreeSet;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::any::TypeId;
+use core::mem::size_of;
+
+use typed_ids::SerialU64;
+
+use super::InternErr;
+use crate::interner::issue_id;
+use crate::stats::type_set_bytes;
+
+/// A [CellStore] holds the values of an interner which issues each new value
+/// the next id in sequence, along with the types each value was interned
+/// under. A value's id is its position in the store. Slot[0] is a sentinel
+/// which never holds a value, and removing a value leaves a tombstone in its
+/// slot, so the ids of the values after it stay valid and are never reused.
+///
+/// The store knows nothing about how values are looked up; each interner
+/// wrapping one adds its own search on top.
+#[derive(Clone, Debug)]
+pub(crate) struct CellStore<V> {
+    cells:   Vec<StoreCell<V>>,
+    // The number of cells which have been emptied by `remove`.
+    removed: usize,
+}
+
+#[derive(Clone, Debug)]
+struct StoreCell<V> {
+    // A removed cell no longer holds a value, but it keeps its
+    // slot so the ids of the cells after it stay valid.
+    value: Option<V>,
+    typs:  BTreeSet<TypeId>,
+}
+
+impl<V> StoreCell<V> {
+    fn tombstone() -> Self {
+        Self {
+            value: None,
+            typs:  BTreeSet::new(),
+        }
+    }
+}
+
+impl<V> CellStore<V> {
+    pub(crate) fn new() -> Self {
+        Self {
+            cells:   vec![StoreCell::tombstone()],
+            removed: 0,
+        }
+    }
+
+    /// [get] returns the value stored at the position, or [None] for the
+    /// sentinel, tombstones, and positions past the end.
+    pub(crate) fn get(&self, position: usize) -> Option<&V> {
+        self.cells.get(position)?.value.as_ref()
+    }
+
+    /// [types] returns the types the value at the position was interned
+    /// under, which is empty for the sentinel and tombstones.
+    pub(crate) fn types(&self, position: usize) -> Vec<TypeId> {
+        self.cells
+            .get(position)
+            .map(|cell| cell.typs.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn has_type(&self, position: usize, typ: &TypeId) -> bool {
+        self.cells
+            .get(position)
+            .is_some_and(|cell| cell.typs.contains(typ))
+    }
+
+    /// [add_type] records that the value at the position was also interned
+    /// under the type.
+    pub(crate) fn add_type(&mut self, position: usize, typ: TypeId) {
+        self.cells[position].typs.insert(typ);
+    }
+
+    /// [position] returns the position of the first live value which
+    /// `matches` accepts, scanning in id order.
+    pub(crate) fn position(&self, matches: impl Fn(&V) -> bool) -> Option<usize> {
+        self.iter()
+            .find(|(_, value, _)| matches(value))
+            .map(|(position, _, _)| position)
+    }
+
+    /// [push] stores a new value under the next id, interned under the type.
+    /// It fails without storing anything if that id is out of range.
+    pub(crate) fn push(&mut self, value: V, typ: TypeId) -> Result<usize, InternErr> {
+        let position = self.cells.len();
+        issue_id(position as u64)?;
+        let mut typs = BTreeSet::new();
+        typs.insert(typ);
+        self.cells.push(StoreCell {
+            value: Some(value),
+            typs,
+        });
+        Ok(position)
+    }
+
+    /// [restore] stores a value at a position past the end, as when reloading
+    /// a dump. Any gap before it is filled with tombstones, so the value
+    /// keeps its original id.
+    #[cfg(feature = "std")]
+    pub(crate) fn restore(&mut self, position: usize, value: V, typs: Vec<TypeId>) {
+        while self.cells.len() < position {
+            self.cells.push(StoreCell::tombstone());
+            self.removed += 1;
+        }
+        self.cells.push(StoreCell {
+            value: Some(value),
+            typs:  typs.into_iter().collect(),
+        });
+    }
+
+    /// [iter] walks every live value in id order, with its position and the
+    /// types it was interned under.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (usize, &V, &BTreeSet<TypeId>)> {
+        // Skip the sentinel cell along with any tombstones.
+        self.cells
+            .iter()
+            .enumerate()
+            .skip(1)
+            .filter_map(|(position, cell)| Some((position, cell.value.as_ref()?, &cell.typs)))
+    }
+
+    /// [entries] lists every live value as [Interner::entries] does, using
+    /// `to_string` to recover each value's string.
+    pub(crate) fn entries(
+        &self,
+        to_string: impl Fn(&V) -> String,
+    ) -> Vec<(SerialU64<()>, String, Vec<TypeId>)> {
+        self.iter()
+            .map(|(position, value, typs)| {
+                let typs = typs.iter().cloned().collect();
+                (to_id(position), to_string(value), typs)
+            })
+            .collect()
+    }
+
+    /// [remove] detaches the type from the value at the position, returning
+    /// false if it wasn't interned under that type. Once no types refer to
+    /// the value, it is freed, leaving a tombstone behind.
+    pub(crate) fn remove(&mut self, position: usize, typ: &TypeId) -> bool {
+        // The sentinel cell is never removed.
+        if position == 0 {
+            return false;
+        }
+        let cell = match self.cells.get_mut(position) {
+            Some(cell) => cell,
+            None => return false,
+        };
+        if !cell.typs.remove(typ) {
+            return false;
+        }
+        // • Once no types refer to the cell, free its value.
+        if cell.typs.is_empty() {
+            cell.value = None;
+            self.removed += 1;
+        }
+        true
+    }
+
+    /// [clear] drops every value, keeping only the sentinel cell.
+    pub(crate) fn clear(&mut self) {
+        self.cells.truncate(1);
+        self.removed = 0;
+    }
+
+    /// [take_values] empties the store like [clear], moving every live
+    /// value out in id order.
+    pub(crate) fn take_values(&mut self) -> Vec<V> {
+        self.removed = 0;
+        self.cells
+            .drain(1..)
+            .filter_map(|cell| cell.value)
+            .collect()
+    }
+
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        self.cells.reserve(additional);
+    }
+
+    pub(crate) fn shrink_to_fit(&mut self) {
+        self.cells.shrink_to_fit();
+    }
+
+    #[cfg(test)]
+    pub(crate) fn capacity(&self) -> usize {
+        self.cells.capacity()
+    }
+
+    /// [next_id] returns the id the next pushed value will be stored under.
+    pub(crate) fn next_id(&self) -> u64 {
+        self.cells.len() as u64
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        // Don't count the sentinel cell or tombstones.
+        self.cells.len() - 1 - self.removed
+    }
+
+    /// [num_type_associations] counts the (value, type) pairs in the store.
+    pub(crate) fn num_type_associations(&self) -> usize {
+        self.cells.iter().map(|cell| cell.typs.len()).sum()
+    }
+
+    /// [heap_size] estimates the bytes the store holds on the heap, using
+    /// `value_bytes` to measure what each live value holds.
+    pub(crate) fn heap_size(&self, value_bytes: impl Fn(&V) -> usize) -> usize {
+        let cells = self.cells.capacity() * size_of::<StoreCell<V>>();
+        let contents: usize = self
+            .cells
+            .iter()
+            .map(|cell| cell.value.as_ref().map_or(0, &value_bytes) + type_set_bytes(&cell.typs))
+            .sum();
+        cells + contents
+    }
+}
+
+impl<V> Default for CellStore<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// [to_id] converts a position in a [CellStore] into its id. Every position
+/// in a store was range-checked when its value was pushed.
+pub(crate) fn to_id<T>(position: usize) -> SerialU64<T> {
+    SerialU64::try_from(position as u64).unwrap()
+}
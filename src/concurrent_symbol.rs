@@ -0,0 +1,95 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use std::{fmt, ptr};
+
+use typed_ids::SerialU64;
+
+use crate::errors::parse_resolved;
+use crate::internable::Internable;
+use crate::symbol::Resolvable;
+use crate::{Interner, ResolutionErr};
+
+/// A [ConcurrentSymbol] is the thread-safe counterpart of a [Symbol]. It is
+/// issued by a [ConcurrentSymbolTable], and may be sent to and shared between
+/// threads as long as `T` can be. Comparisons between [ConcurrentSymbol]s
+/// are O(1).
+#[derive(Clone)]
+pub struct ConcurrentSymbol<T: Internable + 'static> {
+    // This ID maps the Symbol to an entry in the table.
+    id:     SerialU64<T>,
+    // This is a reference to the table storing the Symbol.
+    lookup: Arc<dyn Resolvable + Send + Sync>,
+}
+
+impl<T: Internable + 'static> ConcurrentSymbol<T> {
+    /// [new] will construct a new ConcurrentSymbol. This method is only
+    /// intended for internal use.
+    pub fn new<R: Resolvable + Send + Sync + 'static>(id: SerialU64<T>, lookup: R) -> Self {
+        let lookup = Arc::new(lookup);
+        Self { id, lookup }
+    }
+
+    pub fn id(&self) -> SerialU64<T> {
+        self.id
+    }
+
+    pub fn erase_type(&self) -> SerialU64<()> {
+        let id_unwrapped = self.id.get();
+        SerialU64::<()>::try_from(id_unwrapped).unwrap()
+    }
+
+    pub fn origin(&self) -> *const (dyn Interner + 'static) {
+        self.lookup.addr()
+    }
+
+    /// [resolve] recovers the interned value from the table holding this
+    /// [ConcurrentSymbol], without the caller needing a reference to the table.
+    pub fn resolve(&self) -> Result<T, ResolutionErr<T>> {
+        let erased = self.erase_type();
+        let interned_string = self
+            .lookup
+            .resolve(erased)
+            .ok_or(ResolutionErr::UnknownIdErr)?;
+        parse_resolved(&interned_string)
+    }
+}
+
+impl<T: Internable + 'static> fmt::Display for ConcurrentSymbol<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let recovered_item: T = self.resolve().map_err(|_| fmt::Error)?;
+        write!(f, "{}", recovered_item.as_ref())
+    }
+}
+
+impl<T: Internable + 'static> fmt::Debug for ConcurrentSymbol<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl<T: Internable + 'static> PartialEq for ConcurrentSymbol<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && ptr::addr_eq(self.lookup.addr(), other.lookup.addr())
+    }
+}
+
+impl<T: Internable + 'static> Eq for ConcurrentSymbol<T> {}
+
+impl<T: Internable + 'static> Hash for ConcurrentSymbol<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl<T: Internable + 'static> Ord for ConcurrentSymbol<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
+impl<T: Internable + 'static> PartialOrd for ConcurrentSymbol<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
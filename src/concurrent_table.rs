@@ -0,0 +1,168 @@
+use std::any::TypeId;
+use std::ptr;
+use std::sync::{Arc, RwLock};
+
+use typed_ids::SerialU64;
+
+use super::{
+    ConcurrentSymbol, InternErr, Internable, Interner, InternerFlavor, ResolutionErr, Resolvable,
+    TableMismatchErr,
+};
+use crate::errors::parse_resolved;
+use crate::flavor::FromInterner;
+
+/// A [ConcurrentSymbolTable] is a [SymbolTable] which can be shared across
+/// threads. Cloning the table produces another handle onto the same store,
+/// and every handle can intern through a shared reference. Reads take a
+/// shared lock on the backing [Interner], while interning takes an exclusive
+/// lock.
+#[derive(Clone)]
+pub struct ConcurrentSymbolTable {
+    interner: Arc<RwLock<dyn Interner + Send + Sync>>,
+}
+
+impl ConcurrentSymbolTable {
+    pub fn new(flavor: InternerFlavor) -> Self {
+        flavor.build()
+    }
+
+    /// The [intern] function takes any object which can be converted
+    /// to and from a [String], and interns it into the table. Unlike
+    /// [SymbolTable::intern], it only requires a shared reference, since
+    /// the interner is locked internally. Like [SymbolTable::intern], it
+    /// panics if the interner has run out of ids; use [try_intern] to handle
+    /// that case instead.
+    pub fn intern<T: Internable>(&self, item: &T) -> ConcurrentSymbol<T> {
+        match self.try_intern(item) {
+            Ok(sym) => sym,
+            Err(err) => panic!("failed to intern: {}", err),
+        }
+    }
+
+    /// [try_intern] is like [intern], but returns an [InternErr] instead of
+    /// panicking when the interner can't issue an id for a new string.
+    pub fn try_intern<T: Internable>(&self, item: &T) -> Result<ConcurrentSymbol<T>, InternErr> {
+        let str_repr: String = item.as_ref().to_string();
+        let typ_id = TypeId::of::<T>();
+        let erased_id = self.interner.write().unwrap().intern(str_repr, typ_id)?;
+        Ok(self.to_typed_symbol(erased_id))
+    }
+
+    /// Resolve returns the object that was originally stored in the table.
+    /// If this [ConcurrentSymbol] was created by a table other than `self`, or
+    /// its id is unknown to the table, then [resolve] returns a
+    /// [ResolutionErr].
+    pub fn resolve<T: Internable + 'static>(
+        &self,
+        sym: &ConcurrentSymbol<T>,
+    ) -> Result<T, ResolutionErr<T>> {
+        let table_addr = self.addr();
+        let sym_addr = sym.origin();
+        if !ptr::addr_eq(table_addr, sym_addr) {
+            let err = ResolutionErr::from(TableMismatchErr::new(table_addr, sym_addr));
+            return Err(err);
+        }
+        let id = sym.erase_type();
+        let interner = self.interner.read().unwrap();
+        let resolution = interner
+            .resolve_ref(id)
+            .ok_or(ResolutionErr::UnknownIdErr)?;
+        parse_resolved(&resolution)
+    }
+
+    pub fn get_interned<T: Internable + 'static, S: AsRef<str>>(
+        &self,
+        val: S,
+    ) -> Option<ConcurrentSymbol<T>> {
+        let str_repr: String = val.as_ref().to_string();
+        let typ_id = TypeId::of::<T>();
+        let id = self
+            .interner
+            .read()
+            .unwrap()
+            .get_interned(str_repr, typ_id)?;
+        Some(self.to_typed_symbol(id))
+    }
+
+    pub fn has_interned<T: Internable + 'static, S: AsRef<str>>(&self, val: S) -> bool {
+        self.get_interned::<T, S>(val).is_some()
+    }
+
+    fn to_typed_symbol<T: Internable>(&self, id: SerialU64<()>) -> ConcurrentSymbol<T> {
+        let upcast_id = SerialU64::<T>::try_from(id.get()).unwrap();
+        ConcurrentSymbol::new(upcast_id, self.clone())
+    }
+}
+
+impl Resolvable for ConcurrentSymbolTable {
+    fn resolve(&self, id: SerialU64<()>) -> Option<String> {
+        self.interner.read().unwrap().resolve(id)
+    }
+
+    // As with the SymbolTable, the address of the underlying interner
+    // is the only truly stable memory address.
+    fn addr(&self) -> *const (dyn Interner + 'static) {
+        let guard = self.interner.read().unwrap();
+        &*guard as *const (dyn Interner + Send + Sync) as *const (dyn Interner + 'static)
+    }
+}
+
+impl<T: Interner + Send + Sync + 'static> From<T> for ConcurrentSymbolTable {
+    fn from(interner: T) -> Self {
+        let lock = RwLock::new(interner);
+        Self {
+            interner: Arc::new(lock),
+        }
+    }
+}
+
+impl FromInterner for ConcurrentSymbolTable {
+    fn from_interner<I: Interner + Send + Sync + 'static>(interner: I) -> Self {
+        Self::from(interner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use static_assertions::assert_impl_all;
+
+    use super::{ConcurrentSymbol, ConcurrentSymbolTable, InternerFlavor};
+
+    #[test]
+    fn table_is_send_and_sync() {
+        assert_impl_all!(ConcurrentSymbolTable: Send, Sync);
+        assert_impl_all!(ConcurrentSymbol<String>: Send, Sync);
+    }
+
+    #[test]
+    fn threads_share_symbols() {
+        let table = ConcurrentSymbolTable::new(InternerFlavor::Array);
+        let words = ["frog", "toad", "newt", "frog", "toad"];
+        let handles: Vec<_> = (0..4)
+            .map(|offset| {
+                let table = table.clone();
+                thread::spawn(move || {
+                    words
+                        .iter()
+                        .cycle()
+                        .skip(offset)
+                        .take(words.len())
+                        .map(|word| (word.to_string(), table.intern(&word.to_string())))
+                        .collect::<Vec<(String, ConcurrentSymbol<String>)>>()
+                })
+            })
+            .collect();
+        let results: Vec<_> = handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect();
+
+        for (word, sym) in &results {
+            let expected: ConcurrentSymbol<String> = table.get_interned(word).unwrap();
+            assert_eq!(sym, &expected);
+            assert_eq!(table.resolve(sym).as_ref(), Ok(word));
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use std::any::TypeId;
+use std::collections::HashSet;
+
+use typed_ids::SerialU64;
+
+use super::type_registry;
+use crate::DecodeErr;
+
+// The flat format written by SymbolTable::to_bytes. Every integer is a
+// little-endian u64. The header holds the entry count, then the number of
+// ids below the last entry which were removed. Each entry follows in id
+// order:
+//
+//   id | string length | string bytes | type count | type indices...
+//
+// Every id lies within the entry count plus the removed count, so a
+// decoded table never holds more slots than the header declares.
+// Type indices come from the type registry, so like the serde format, a
+// dump can only be decoded by the process that encoded it.
+
+// An entry as produced by Interner::entries.
+type Entry = (SerialU64<()>, String, Vec<TypeId>);
+
+/// [encode] writes the entries, as produced by [Interner::entries],
+/// in the flat format.
+pub(crate) fn encode(entries: Vec<Entry>) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    let put = |bytes: &mut Vec<u8>, val: u64| bytes.extend_from_slice(&val.to_le_bytes());
+    // • Every id below the last entry which isn't an entry was removed.
+    let last_id = entries.last().map_or(0, |(id, _, _)| id.get());
+    put(&mut bytes, entries.len() as u64);
+    put(&mut bytes, last_id - entries.len() as u64);
+    for (id, value, typs) in entries {
+        put(&mut bytes, id.get());
+        put(&mut bytes, value.len() as u64);
+        bytes.extend_from_slice(value.as_bytes());
+        put(&mut bytes, typs.len() as u64);
+        for typ in typs {
+            put(&mut bytes, type_registry::index_of(typ));
+        }
+    }
+    bytes
+}
+
+/// [decode] reads entries written by [encode], checking that ids are
+/// strictly increasing, never the sentinel, and within the bound declared
+/// by the header. Every entry must have a type, and no two entries may hold
+/// the same string.
+pub(crate) fn decode(bytes: &[u8]) -> Result<Vec<Entry>, DecodeErr> {
+    let mut reader = Reader { bytes };
+    let count = reader.u64()?;
+    let removed = reader.u64()?;
+    let max_id = count.saturating_add(removed);
+    let mut entries = Vec::new();
+    let mut last_id = 0;
+    for _ in 0..count {
+        let raw_id = reader.u64()?;
+        if raw_id <= last_id {
+            return Err(DecodeErr::OutOfOrderId(raw_id));
+        }
+        if raw_id > max_id {
+            return Err(DecodeErr::InvalidId(raw_id));
+        }
+        last_id = raw_id;
+        let id = SerialU64::try_from(raw_id).map_err(|_| DecodeErr::InvalidId(raw_id))?;
+        let len = reader.u64()?;
+        let value =
+            String::from_utf8(reader.take(len)?.to_vec()).map_err(|_| DecodeErr::InvalidUtf8)?;
+        let num_typs = reader.u64()?;
+        if num_typs == 0 {
+            return Err(DecodeErr::UntypedEntry(raw_id));
+        }
+        let typs = (0..num_typs)
+            .map(|_| {
+                let index = reader.u64()?;
+                type_registry::type_at(index).ok_or(DecodeErr::UnknownType(index))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        entries.push((id, value, typs));
+    }
+    if !reader.bytes.is_empty() {
+        return Err(DecodeErr::TrailingBytes);
+    }
+    // • Each string may only be stored once, or lookups would disagree
+    //   about which id it has.
+    let mut seen = HashSet::new();
+    if let Some((id, _, _)) = entries.iter().find(|(_, value, _)| !seen.insert(value)) {
+        return Err(DecodeErr::DuplicateString(id.get()));
+    }
+    Ok(entries)
+}
+
+// Consumes the input from the front.
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: u64) -> Result<&'a [u8], DecodeErr> {
+        let len = usize::try_from(len).map_err(|_| DecodeErr::Truncated)?;
+        if len > self.bytes.len() {
+            return Err(DecodeErr::Truncated);
+        }
+        let (head, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(head)
+    }
+
+    fn u64(&mut self) -> Result<u64, DecodeErr> {
+        let head = self.take(8)?;
+        Ok(u64::from_le_bytes(head.try_into().unwrap()))
+    }
+}
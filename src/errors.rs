@@ -1,16 +1,27 @@
-use std::fmt;
-use std::marker::PhantomData;
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use core::fmt;
+use core::marker::PhantomData;
 
+#[cfg(feature = "diagnostics")]
 use colored::Colorize;
+#[cfg(feature = "diagnostics")]
 use miette::Diagnostic;
-use thiserror::Error;
 
 use crate::{Internable, Interner};
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum ResolutionErr<T: Internable + 'static> {
     MismatchErr(TableMismatchErr<T>),
-    ParseErr,
+    /// The interned string could not be parsed back into a `T`.
+    ParseErr {
+        raw: String,
+    },
+    /// The [Symbol]'s id is not present in the table's [Interner].
+    UnknownIdErr,
+    /// The [Symbol] was issued before its table was last cleared or drained,
+    /// so its id no longer means what it did.
+    StaleSymbolErr,
 }
 
 impl<T: Internable + 'static> From<TableMismatchErr<T>> for ResolutionErr<T> {
@@ -19,17 +30,110 @@ impl<T: Internable + 'static> From<TableMismatchErr<T>> for ResolutionErr<T> {
     }
 }
 
+impl<T: Internable + 'static> fmt::Display for ResolutionErr<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            ResolutionErr::MismatchErr(_) => write!(f, "the Symbol came from another table"),
+            ResolutionErr::ParseErr { raw } => write!(f, "could not parse {:?}", raw),
+            ResolutionErr::UnknownIdErr => write!(f, "the Symbol's id is unknown to the table"),
+            ResolutionErr::StaleSymbolErr => {
+                write!(f, "the Symbol was issued before the table was cleared")
+            }
+        }
+    }
+}
+
+// The source of a mismatch is the TableMismatchErr itself. A ParseErr has
+// no source, since the error type of a `T: TryFrom<String>` need not
+// implement Error, so it is dropped.
+#[cfg(feature = "std")]
+impl<T: Internable + fmt::Debug + 'static> std::error::Error for ResolutionErr<T> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ResolutionErr::MismatchErr(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// [InternErr] occurs when a string can't be interned.
+#[derive(Debug, PartialEq, Eq)]
+pub enum InternErr {
+    /// The string is new to the table, but every id the table can issue
+    /// is already taken.
+    IdSpaceExhausted,
+}
+
+impl fmt::Display for InternErr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            InternErr::IdSpaceExhausted => write!(f, "the table has run out of ids"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InternErr {}
+
+/// [DecodeErr] occurs when [SymbolTable::from_bytes] is given bytes which
+/// weren't produced by [SymbolTable::to_bytes] in this process.
+#[derive(Debug, PartialEq, Eq)]
+pub enum DecodeErr {
+    /// The input ended partway through an entry.
+    Truncated,
+    /// The input continued after the last entry.
+    TrailingBytes,
+    /// A stored string was not valid UTF-8.
+    InvalidUtf8,
+    /// An id was not greater than the id before it, or was the sentinel.
+    OutOfOrderId(u64),
+    /// An id could not be converted into a [Symbol] id, or exceeded the
+    /// number of ids the header declares.
+    InvalidId(u64),
+    /// A type index was never assigned by this process.
+    UnknownType(u64),
+    /// The entry with this id wasn't interned under any type.
+    UntypedEntry(u64),
+    /// The entry with this id held a string already stored under an
+    /// earlier id.
+    DuplicateString(u64),
+}
+
+impl fmt::Display for DecodeErr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            DecodeErr::Truncated => write!(f, "the input ended partway through an entry"),
+            DecodeErr::TrailingBytes => write!(f, "the input continued after the last entry"),
+            DecodeErr::InvalidUtf8 => write!(f, "a stored string was not valid UTF-8"),
+            DecodeErr::OutOfOrderId(id) => write!(f, "out of order id {}", id),
+            DecodeErr::InvalidId(id) => write!(f, "invalid id {}", id),
+            DecodeErr::UnknownType(index) => write!(f, "unknown type index {}", index),
+            DecodeErr::UntypedEntry(id) => write!(f, "entry {} has no types", id),
+            DecodeErr::DuplicateString(id) => write!(f, "entry {} repeats an earlier string", id),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeErr {}
+
+/// [parse_resolved] converts a string borrowed from an [Interner] back into
+/// the type it was interned as. The string is only copied a second time, for
+/// the error, if it fails to parse.
+pub(crate) fn parse_resolved<T: Internable + 'static>(raw: &str) -> Result<T, ResolutionErr<T>> {
+    T::try_from(raw.to_owned()).map_err(|_| ResolutionErr::ParseErr {
+        raw: raw.to_owned(),
+    })
+}
+
 // TODO Fix error defintion.
 /// [ResolutionErr] occurs when a [Symbol] is resolved on a [SymbolTable] from
 /// which it did not originate. If a user creates two separate [SymbolTable]s,
 /// a [Symbol] from one table is not available to be resolved by the other
 /// table. If a user attempts this, a [ResolutionErr] is returned, indicating
 /// that the identities of the two tables are different.
-#[derive(PartialEq, Eq, Error, Diagnostic)]
-#[error(
-    "This Symbol did not originate from this table. The Symbol's originator has the address xxxx \
-     but this table's address is xxxx"
-)]
+#[derive(PartialEq, Eq)]
+#[cfg_attr(feature = "diagnostics", derive(Diagnostic))]
 pub struct TableMismatchErr<T: Internable + 'static> {
     table_address:  *const (dyn Interner + 'static),
     symbol_address: *const (dyn Interner + 'static),
@@ -49,7 +153,29 @@ impl<T: Internable + 'static> TableMismatchErr<T> {
     }
 }
 
+impl<T: Internable + 'static> fmt::Display for TableMismatchErr<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "This Symbol did not originate from this table. The Symbol's originator has the \
+             address {:?} but this table's address is {:?}",
+            self.symbol_address, self.table_address
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Internable + 'static> std::error::Error for TableMismatchErr<T> {}
+
+// With diagnostics, the addresses are highlighted in color. Without them,
+// Debug matches Display.
 impl<T: Internable + 'static> fmt::Debug for TableMismatchErr<T> {
+    #[cfg(not(feature = "diagnostics"))]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        fmt::Display::fmt(self, f)
+    }
+
+    #[cfg(feature = "diagnostics")]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
         let table_msg = format!("{:?}", self.table_address).red().bold();
         let sym_msg = format!("{:?}", self.symbol_address).red().bold();
@@ -61,3 +187,50 @@ impl<T: Internable + 'static> fmt::Debug for TableMismatchErr<T> {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::symbol::Resolvable;
+    use crate::{InternerFlavor, ResolutionErr, Symbol, SymbolTable};
+
+    #[test]
+    fn display_shows_addresses() {
+        let mut table1 = SymbolTable::new(InternerFlavor::Array);
+        let table2 = SymbolTable::new(InternerFlavor::Array);
+        let sym: Symbol<String> = table1.intern(&"hello".to_owned());
+        let err = match table2.resolve(&sym) {
+            Err(ResolutionErr::MismatchErr(err)) => err,
+            other => panic!("Expected a mismatch error, got {:?}", other),
+        };
+        let msg = err.to_string();
+        assert!(msg.contains(&format!("{:?}", table1.addr())));
+        assert!(msg.contains(&format!("{:?}", table2.addr())));
+    }
+
+    #[cfg(not(feature = "diagnostics"))]
+    #[test]
+    fn debug_is_plain() {
+        let mut table1 = SymbolTable::new(InternerFlavor::Array);
+        let table2 = SymbolTable::new(InternerFlavor::Array);
+        let sym: Symbol<String> = table1.intern(&"hello".to_owned());
+        let err = match table2.resolve(&sym) {
+            Err(ResolutionErr::MismatchErr(err)) => err,
+            other => panic!("Expected a mismatch error, got {:?}", other),
+        };
+        assert_eq!(format!("{:?}", err), err.to_string());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn mismatch_is_the_source() {
+        use std::error::Error;
+
+        let mut table1 = SymbolTable::new(InternerFlavor::Array);
+        let table2 = SymbolTable::new(InternerFlavor::Array);
+        let sym: Symbol<String> = table1.intern(&"hello".to_owned());
+        let err = table2.resolve(&sym).unwrap_err();
+        let source = err.source().expect("a mismatch should have a source");
+        assert!(source.to_string().contains("did not originate"));
+        assert!(ResolutionErr::<String>::UnknownIdErr.source().is_none());
+    }
+}
@@ -1,3 +1,58 @@
+use alloc::boxed::Box;
+
+use super::{
+    ArrayInterner, BoundedInterner, BufferInterner, FxHashInterner, Interner, RadixInterner,
+    SortedInterner, SuffixIndexInterner, SuffixInterner,
+};
+
 pub enum InternerFlavor {
     Array,
+    Radix,
+    Suffix,
+    /// Holds at most this many strings, evicting the least recently
+    /// used. See [BoundedInterner]. A capacity of zero is treated as one,
+    /// since the interner must hold the string it just interned.
+    Bounded(usize),
+    Buffer,
+    FxHash,
+    /// Looks strings up by binary search. See [SortedInterner].
+    Sorted,
+    /// Also indexes strings by suffix. See [SuffixIndexInterner].
+    SuffixIndex,
+}
+
+/// [FromInterner] is implemented by everything which can be built around an
+/// empty interner of some [InternerFlavor], so the flavors are only matched
+/// on in one place.
+pub(crate) trait FromInterner {
+    fn from_interner<I: Interner + Send + Sync + 'static>(interner: I) -> Self;
+}
+
+impl FromInterner for Box<dyn Interner + Send + Sync> {
+    fn from_interner<I: Interner + Send + Sync + 'static>(interner: I) -> Self {
+        Box::new(interner)
+    }
+}
+
+impl InternerFlavor {
+    // Builds `R` around an empty interner of this flavor.
+    pub(crate) fn build<R: FromInterner>(&self) -> R {
+        match self {
+            InternerFlavor::Array => R::from_interner(ArrayInterner::new()),
+            InternerFlavor::Radix => R::from_interner(RadixInterner::new()),
+            InternerFlavor::Suffix => R::from_interner(SuffixInterner::new()),
+            InternerFlavor::Bounded(capacity) => {
+                R::from_interner(BoundedInterner::new((*capacity).max(1)))
+            }
+            InternerFlavor::Buffer => R::from_interner(BufferInterner::new()),
+            InternerFlavor::FxHash => R::from_interner(FxHashInterner::new()),
+            InternerFlavor::Sorted => R::from_interner(SortedInterner::new()),
+            InternerFlavor::SuffixIndex => R::from_interner(SuffixIndexInterner::new()),
+        }
+    }
+
+    // Constructs an empty interner of this flavor.
+    pub(crate) fn interner(&self) -> Box<dyn Interner + Send + Sync> {
+        self.build()
+    }
 }
@@ -0,0 +1,193 @@
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::any::TypeId;
+use core::ptr;
+
+use typed_ids::SerialU64;
+
+use super::{Internable, ResolutionErr, Resolvable, Symbol, SymbolTable, TableMismatchErr};
+use crate::errors::parse_resolved;
+
+/// A [FrozenSymbolTable] is a read-only copy of a [SymbolTable], made by
+/// [SymbolTable::freeze]. It can no longer intern, but resolving a [Symbol] is a plain index into a slice,
+/// with no [RefCell] borrow, and [get_interned] is a binary search. Symbols
+/// issued by the original table resolve against the frozen one, and Symbols
+/// returned by [get_interned] are interchangeable with the original table's.
+#[derive(Clone)]
+pub struct FrozenSymbolTable {
+    // Indexed by id. Slot[0] is the sentinel, which like removed ids is empty.
+    cells:      Rc<[FrozenCell]>,
+    // The ids of the live cells, sorted by their strings.
+    order:      Rc<[usize]>,
+    // The table this was frozen from. Holding it keeps the address
+    // Symbols are checked against from being reused by another table.
+    original:   SymbolTable,
+    // The original's generation when it was frozen. Symbols from before
+    // then are stale, and so are any from clones which clear it later.
+    generation: u64,
+}
+
+struct FrozenCell {
+    value: Option<String>,
+    typs:  Vec<TypeId>,
+}
+
+impl FrozenSymbolTable {
+    pub(crate) fn new(original: SymbolTable) -> Self {
+        // • Copy the entries rather than moving them out, since clones of
+        //   the table, and the Symbols it issued, still share the interner.
+        let entries = original.interner().entries();
+        let size = entries
+            .iter()
+            .map(|(id, _, _)| id.get() as usize + 1)
+            .max()
+            .unwrap_or(1);
+        let mut cells: Vec<FrozenCell> = (0..size)
+            .map(|_| FrozenCell {
+                value: None,
+                typs:  Vec::new(),
+            })
+            .collect();
+        for (id, value, typs) in entries {
+            cells[id.get() as usize] = FrozenCell {
+                value: Some(value),
+                typs,
+            };
+        }
+        let mut order: Vec<usize> = (0..cells.len())
+            .filter(|position| cells[*position].value.is_some())
+            .collect();
+        order.sort_unstable_by(|a, b| cells[*a].value.cmp(&cells[*b].value));
+        let generation = original.generation();
+        Self {
+            cells: cells.into(),
+            order: order.into(),
+            original,
+            generation,
+        }
+    }
+
+    // Finds the position of the cell holding the string, if any.
+    fn position(&self, val: &str) -> Option<usize> {
+        let rank = self
+            .order
+            .binary_search_by(|position| self.cells[*position].value.as_deref().cmp(&Some(val)))
+            .ok()?;
+        Some(self.order[rank])
+    }
+
+    /// Resolve returns the object that was originally stored in the table.
+    /// It fails just like [SymbolTable::resolve] if the [Symbol] came from
+    /// a different table, was made stale by clearing the table before it was
+    /// frozen, or its id is unknown to this one.
+    pub fn resolve<T: Internable + 'static>(&self, sym: &Symbol<T>) -> Result<T, ResolutionErr<T>> {
+        let table_addr = self.original.addr();
+        let sym_addr = sym.origin();
+        if !ptr::addr_eq(table_addr, sym_addr) {
+            let err = ResolutionErr::from(TableMismatchErr::new(table_addr, sym_addr));
+            return Err(err);
+        }
+        // • The Symbol must also have been issued since the table was
+        //   last cleared before freezing.
+        if sym.generation() != self.generation {
+            return Err(ResolutionErr::StaleSymbolErr);
+        }
+        let resolution = self
+            .cells
+            .get(sym.erase_type().get() as usize)
+            .and_then(|cell| cell.value.as_deref())
+            .ok_or(ResolutionErr::UnknownIdErr)?;
+        parse_resolved(resolution)
+    }
+
+    pub fn get_interned<T: Internable + 'static, S: AsRef<str>>(
+        &self,
+        val: S,
+    ) -> Option<Symbol<T>> {
+        let typ_id = TypeId::of::<T>();
+        let position = self
+            .position(val.as_ref())
+            .filter(|position| self.cells[*position].typs.contains(&typ_id))?;
+        let id = SerialU64::<T>::try_from(position as u64).ok()?;
+        let original = self.original.clone();
+        Some(Symbol::at_generation(id, original, self.generation))
+    }
+
+    pub fn has_interned<T: Internable + 'static, S: AsRef<str>>(&self, val: S) -> bool {
+        self.get_interned::<T, S>(val).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{InternerFlavor, ResolutionErr, Symbol, SymbolTable};
+
+    #[test]
+    fn original_symbols_resolve() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let frog: Symbol<String> = table.intern(&"frog".to_owned());
+        let toad: Symbol<String> = table.intern(&"toad".to_owned());
+        let newt: Symbol<String> = table.intern(&"newt".to_owned());
+        table.remove(&toad);
+        let frozen = table.freeze();
+        assert_eq!(frozen.resolve(&frog), Ok("frog".to_owned()));
+        assert_eq!(frozen.resolve(&newt), Ok("newt".to_owned()));
+        assert!(frozen.resolve(&toad).is_err());
+        assert_eq!(frozen.get_interned::<String, _>("newt"), Some(newt));
+        assert!(!frozen.has_interned::<String, _>("toad"));
+        assert!(!frozen.has_interned::<Box<str>, _>("frog"));
+    }
+
+    #[test]
+    fn foreign_symbols_fail() {
+        let frozen = SymbolTable::new(InternerFlavor::Array).freeze();
+        let mut other = SymbolTable::new(InternerFlavor::Array);
+        let frog: Symbol<String> = other.intern(&"frog".to_owned());
+        assert!(frozen.resolve(&frog).is_err());
+    }
+
+    #[test]
+    fn lookups_use_the_index() {
+        let mut table = SymbolTable::new(InternerFlavor::Radix);
+        let words = ["toad", "frog", "", "newt", "toadstool"];
+        let syms: Vec<Symbol<String>> = words.iter().map(|word| table.intern_str(word)).collect();
+        let frozen = table.freeze();
+        for (word, sym) in words.iter().zip(&syms) {
+            assert_eq!(frozen.resolve(sym), Ok(word.to_string()));
+            assert_eq!(frozen.get_interned::<String, _>(word).as_ref(), Some(sym));
+        }
+        assert!(!frozen.has_interned::<String, _>("salamander"));
+    }
+
+    #[test]
+    fn clones_keep_interning_after_freeze() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let apple: Symbol<String> = table.intern_str("apple");
+        let mut clone = table.clone();
+        let frozen = table.freeze();
+        let banana: Symbol<String> = clone.intern_str("banana");
+        assert_ne!(apple, banana);
+        assert_eq!(apple.to_string(), "apple");
+        assert_eq!(frozen.resolve(&apple), Ok("apple".to_owned()));
+        assert!(frozen.resolve(&banana).is_err());
+        assert_eq!(clone.resolve(&apple), Ok("apple".to_owned()));
+    }
+
+    #[test]
+    fn stale_symbols_fail() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let apple: Symbol<String> = table.intern_str("apple");
+        table.clear();
+        let banana: Symbol<String> = table.intern_str("banana");
+        let mut clone = table.clone();
+        let frozen = table.freeze();
+        assert_eq!(frozen.resolve(&apple), Err(ResolutionErr::StaleSymbolErr));
+        assert_eq!(frozen.resolve(&banana), Ok("banana".to_owned()));
+        // • Clearing a clone afterwards doesn't disturb the frozen copy.
+        clone.clear();
+        let found = frozen.get_interned::<String, _>("banana").unwrap();
+        assert_eq!(frozen.resolve(&found), Ok("banana".to_owned()));
+        assert_eq!(frozen.resolve(&banana), Ok("banana".to_owned()));
+    }
+}
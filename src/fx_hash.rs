@@ -0,0 +1,212 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::any::{Any, TypeId};
+
+use typed_ids::SerialU64;
+
+use super::{InternErr, Interner, TableStats};
+use crate::cell_store::{to_id, CellStore};
+
+/// An [FxHashInterner] is an [ArrayInterner] which also stores a 64-bit
+/// hash of each string. Lookups compare hashes first, and only compare the
+/// full strings when the hashes match, so long strings which differ only
+/// near the end are rejected without being scanned. It still performs
+/// `intern` in O(n), and `resolve` in O(1).
+#[derive(Clone, Debug)]
+pub struct FxHashInterner {
+    store: CellStore<Hashed>,
+}
+
+#[derive(Clone, Debug)]
+struct Hashed {
+    hash:  u64,
+    value: String,
+}
+
+impl FxHashInterner {
+    pub fn new() -> Self {
+        Self {
+            store: CellStore::new(),
+        }
+    }
+
+    // Returns the position of this string in the table. A matching hash
+    // is only a candidate; the strings must also be equal, since two
+    // strings may share a hash.
+    fn position(&self, val: &str) -> Option<usize> {
+        let hash = fx_hash(val);
+        self.store
+            .position(|cell| cell.hash == hash && cell.value == val)
+    }
+}
+
+impl Default for FxHashInterner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Hashes the string with the algorithm used by rustc's FxHasher. It is fast
+// rather than collision-resistant, which suits a hash that only filters
+// candidates before a full comparison.
+fn fx_hash(val: &str) -> u64 {
+    const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+    let mix = |hash: u64, word: u64| (hash.rotate_left(5) ^ word).wrapping_mul(SEED);
+    let mut chunks = val.as_bytes().chunks_exact(8);
+    let mut hash = 0;
+    for chunk in &mut chunks {
+        let word = u64::from_le_bytes(chunk.try_into().unwrap());
+        hash = mix(hash, word);
+    }
+    for byte in chunks.remainder() {
+        hash = mix(hash, u64::from(*byte));
+    }
+    // • Mix in the length, so strings padded with zero bytes differ.
+    mix(hash, val.len() as u64)
+}
+
+impl Interner for FxHashInterner {
+    fn intern(&mut self, val: String, typ: TypeId) -> Result<SerialU64<()>, InternErr> {
+        // • If the string is already stored, add the type to its cell.
+        if let Some(position) = self.position(&val) {
+            self.store.add_type(position, typ);
+            return Ok(to_id(position));
+        }
+        // • Otherwise, append a new cell, remembering the string's hash.
+        let hashed = Hashed {
+            hash:  fx_hash(&val),
+            value: val,
+        };
+        self.store.push(hashed, typ).map(to_id)
+    }
+
+    fn resolve(&self, id: SerialU64<()>) -> Option<String> {
+        Some(self.store.get(id.get() as usize)?.value.clone())
+    }
+
+    fn resolve_ref(&self, id: SerialU64<()>) -> Option<Cow<'_, str>> {
+        let cell = self.store.get(id.get() as usize)?;
+        Some(Cow::Borrowed(cell.value.as_str()))
+    }
+
+    fn get_interned(&self, val: String, typ: TypeId) -> Option<SerialU64<()>> {
+        self.get_interned_str(&val, typ)
+    }
+
+    fn get_interned_str(&self, val: &str, typ: TypeId) -> Option<SerialU64<()>> {
+        self.position(val)
+            .filter(|position| self.store.has_type(*position, &typ))
+            .map(to_id)
+    }
+
+    fn get_interned_any(&self, val: &str) -> Option<SerialU64<()>> {
+        self.position(val).map(to_id)
+    }
+
+    fn entries(&self) -> Vec<(SerialU64<()>, String, Vec<TypeId>)> {
+        self.store.entries(|cell| cell.value.clone())
+    }
+
+    fn types_for(&self, val: &str) -> Vec<TypeId> {
+        self.position(val)
+            .map(|position| self.store.types(position))
+            .unwrap_or_default()
+    }
+
+    fn remove(&mut self, id: SerialU64<()>, typ: TypeId) -> bool {
+        self.store.remove(id.get() as usize, &typ)
+    }
+
+    fn clear(&mut self) {
+        self.store.clear();
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.store.reserve(additional);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.store.shrink_to_fit();
+    }
+
+    fn next_id(&self) -> Option<u64> {
+        Some(self.store.next_id())
+    }
+
+    fn len(&self) -> usize {
+        self.store.len()
+    }
+
+    fn stats(&self) -> TableStats {
+        TableStats {
+            num_entries:           self.len(),
+            total_bytes_stored:    self.store.iter().map(|(_, cell, _)| cell.value.len()).sum(),
+            num_type_associations: self.store.num_type_associations(),
+            estimated_heap_bytes:  self.heap_size(),
+        }
+    }
+
+    fn heap_size(&self) -> usize {
+        self.store.heap_size(|cell| cell.value.capacity())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+
+    use super::{fx_hash, FxHashInterner, Hashed};
+    use crate::{Interner, InternerFlavor, Symbol, SymbolTable};
+
+    #[test]
+    fn same_length_strings_dedup() {
+        let typ = TypeId::of::<String>();
+        let mut interner = FxHashInterner::new();
+        let words = ["aaaa", "aaab", "baaa", "abab", "aaaa", "baaa"];
+        let ids: Vec<_> = words
+            .iter()
+            .map(|word| interner.intern(word.to_string(), typ).unwrap())
+            .collect();
+        assert_eq!(interner.len(), 4);
+        assert_eq!(ids[0], ids[4]);
+        assert_eq!(ids[2], ids[5]);
+        for (word, id) in words.iter().zip(&ids) {
+            assert_eq!(interner.resolve(*id).as_deref(), Some(*word));
+        }
+        assert_ne!(fx_hash("a"), fx_hash("a\0"));
+    }
+
+    #[test]
+    fn colliding_hashes_compare_strings() {
+        let typ = TypeId::of::<String>();
+        let mut interner = FxHashInterner::new();
+        // • Plant a cell whose hash collides with "toad", but whose
+        //   string differs.
+        let impostor = Hashed {
+            hash:  fx_hash("toad"),
+            value: "frog".to_string(),
+        };
+        interner.store.push(impostor, typ).unwrap();
+        assert_eq!(interner.get_interned_str("toad", typ), None);
+        let toad = interner.intern("toad".to_string(), typ).unwrap();
+        assert_eq!(toad.get(), 2);
+        assert_eq!(interner.resolve(toad).as_deref(), Some("toad"));
+        assert_eq!(interner.get_interned_str("toad", typ), Some(toad));
+    }
+
+    #[test]
+    fn fx_hash_flavor() {
+        let mut table = SymbolTable::new(InternerFlavor::FxHash);
+        let toad: Symbol<String> = table.intern(&"toad".to_owned());
+        let again: Symbol<String> = table.intern(&"toad".to_owned());
+        assert_eq!(toad, again);
+        assert_eq!(table.resolve(&toad), Ok("toad".to_owned()));
+        assert!(table.remove(&toad));
+        assert!(table.is_empty());
+    }
+}
@@ -1,3 +1,5 @@
+use alloc::string::String;
+
 /// A type is [Internable] if it supports conversion to and from
 /// [String], and it is static. It doesn't always need to be parsable
 /// from a string, but the output of .toString() must be parsable by TryFrom()
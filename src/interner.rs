@@ -1,7 +1,13 @@
-use std::any::TypeId;
+use alloc::borrow::Cow;
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::any::{Any, TypeId};
 
 use typed_ids::SerialU64;
 
+use crate::{InternErr, TableStats};
+
 // Maps (String, TypeID) => SerialU64 / UUID
 /// [Interner] is a backing store for the [SymbolTable]. It is responsible for
 /// implementing [Symbol] uniqueness and [String] compression. You can provide
@@ -18,11 +24,182 @@ pub trait Interner {
     /// By making the SymbolTable responsible for strengthening the typing
     /// guarantees, the Interner is able to compress `n` types with
     /// the same string represention using O(1) memory.
-    fn intern(&mut self, val: String, typ: TypeId) -> SerialU64<()>;
-    fn resolve(&self, id: SerialU64<()>) -> String;
+    /// Ids start at 1. Id 0 is reserved for the null [Symbol], and must never
+    /// be issued for a real [String].
+    /// If the [String] is new, but every id the interner can issue is
+    /// taken, [intern] fails with [InternErr::IdSpaceExhausted] and leaves
+    /// the interner unchanged.
+    fn intern(&mut self, val: String, typ: TypeId) -> Result<SerialU64<()>, InternErr>;
+    /// [intern_with_status] is like [intern], but also reports whether the
+    /// [String] was newly added, rather than already being stored (perhaps
+    /// under another type). By default, it compares [len] before and after.
+    fn intern_with_status(
+        &mut self,
+        val: String,
+        typ: TypeId,
+    ) -> Result<(SerialU64<()>, bool), InternErr> {
+        let before = self.len();
+        let id = self.intern(val, typ)?;
+        Ok((id, self.len() > before))
+    }
+    /// [intern_reserve] is like [intern], but hints that about `extra` more
+    /// bytes of string data are about to be interned after this one, so the
+    /// interner can grow its storage once, up front. By default, the hint is
+    /// ignored.
+    fn intern_reserve(
+        &mut self,
+        val: String,
+        typ: TypeId,
+        _extra: usize,
+    ) -> Result<SerialU64<()>, InternErr> {
+        self.intern(val, typ)
+    }
+    /// [resolve] returns the [String] stored under the given id, or [None]
+    /// if the id was never issued by this interner.
+    fn resolve(&self, id: SerialU64<()>) -> Option<String>;
+    /// [resolve_ref] is like [resolve], but lends the stored [String] out
+    /// instead of cloning it where possible. Interners which don't store each
+    /// string contiguously can rely on the default, which clones.
+    fn resolve_ref(&self, id: SerialU64<()>) -> Option<Cow<'_, str>> {
+        self.resolve(id).map(Cow::Owned)
+    }
+    /// [resolve_rc] is like [resolve], but returns the string as an
+    /// [Rc<str>]. Interners which store [Rc<str>]s, like the [RcStrInterner],
+    /// hand out clones of them; by default, the resolved [String] is wrapped
+    /// in a new [Rc].
+    fn resolve_rc(&self, id: SerialU64<()>) -> Option<Rc<str>> {
+        self.resolve(id).map(Rc::from)
+    }
+    /// [contains_id] reports whether the id currently refers to a stored
+    /// [String], which is false for the sentinel, for ids the interner never
+    /// issued, and for removed ids. By default, it checks that [resolve_ref]
+    /// succeeds.
+    fn contains_id(&self, id: SerialU64<()>) -> bool {
+        self.resolve_ref(id).is_some()
+    }
     /// [get_interned] returns the untyped id of the Symbol corresponding
     /// to the String, if the string is contained within the store.
     fn get_interned(&self, val: String, typ: TypeId) -> Option<SerialU64<()>>;
+    /// [get_interned_str] is like [get_interned], but borrows the string.
+    /// Interners which can search without an owned [String] should override
+    /// the default, which allocates one.
+    fn get_interned_str(&self, val: &str, typ: TypeId) -> Option<SerialU64<()>> {
+        self.get_interned(String::from(val), typ)
+    }
+    /// [get_interned_any] returns the id of the [String] if it is stored
+    /// under any type at all. By default, it scans [entries].
+    fn get_interned_any(&self, val: &str) -> Option<SerialU64<()>> {
+        self.entries()
+            .into_iter()
+            .find(|(_, value, _)| value == val)
+            .map(|(id, _, _)| id)
+    }
+    /// [with_suffix] returns the id of every [String] interned under the
+    /// [TypeId] which ends with `suffix`, in id order. By default, it scans
+    /// [entries]; interners which index their strings by suffix, like the
+    /// [SuffixIndexInterner], can answer without a scan.
+    fn with_suffix(&self, suffix: &str, typ: TypeId) -> Vec<SerialU64<()>> {
+        self.entries()
+            .into_iter()
+            .filter(|(_, value, typs)| value.ends_with(suffix) && typs.contains(&typ))
+            .map(|(id, _, _)| id)
+            .collect()
+    }
+    /// [entries] returns the id of every interned [String] along with
+    /// the [TypeId]s it was interned under, in id order.
+    fn entries(&self) -> Vec<(SerialU64<()>, String, Vec<TypeId>)>;
+    /// [types_for] returns every [TypeId] the [String] has been interned
+    /// under, or nothing if it isn't stored. By default, it scans [entries].
+    fn types_for(&self, val: &str) -> Vec<TypeId> {
+        self.entries()
+            .into_iter()
+            .find(|(_, value, _)| value == val)
+            .map(|(_, _, typs)| typs)
+            .unwrap_or_default()
+    }
+    /// [strings] returns every interned [String] in id order.
+    fn strings(&self) -> Vec<String> {
+        self.entries()
+            .into_iter()
+            .map(|(_, value, _)| value)
+            .collect()
+    }
+    /// [sorted_strings] returns every interned [String] in lexicographic
+    /// order. By default, it sorts the output of [strings]; interners which
+    /// keep their strings ordered can return them directly.
+    fn sorted_strings(&self) -> Vec<String> {
+        let mut strings = self.strings();
+        strings.sort_unstable();
+        strings
+    }
+    /// [remove] detaches the [TypeId] from the [String] stored under this id,
+    /// returning false if it wasn't interned under that type. Once no types
+    /// remain, the interner may release the string, after which the id no
+    /// longer resolves. Ids are never reissued to other strings.
+    fn remove(&mut self, id: SerialU64<()>, typ: TypeId) -> bool;
+    /// [clear] empties the interner, returning it to its just-constructed
+    /// state. Ids issued before the clear may be reissued afterward.
+    fn clear(&mut self);
+    /// [take_strings] empties the interner like [clear], returning every
+    /// [String] it held in id order. By default, it clones them out with
+    /// [strings] before clearing; interners which own each string outright
+    /// can move them out instead.
+    fn take_strings(&mut self) -> Vec<String> {
+        let strings = self.strings();
+        self.clear();
+        strings
+    }
+    /// [reserve] is a hint that at least `additional` more strings are about
+    /// to be interned, so the interner can allocate room for them up front.
+    /// By default, it does nothing.
+    fn reserve(&mut self, _additional: usize) {}
+    /// [shrink_to_fit] releases any excess capacity the interner is holding.
+    /// It must not change any ids or resolutions. By default, it does nothing.
+    fn shrink_to_fit(&mut self) {}
+    /// [touch] marks the [String] stored under this id as just used, as
+    /// interning it again would, for lookups which find a string without
+    /// going through [intern]. Interners which evict by recency should
+    /// override it. By default, it does nothing.
+    fn touch(&mut self, _id: SerialU64<()>) {}
+    /// [evicts] reports whether interning a [String] may release others, as
+    /// the [BoundedInterner] does once it is full. The outcome then depends
+    /// on the order of every intern, so callers which batch interns, like
+    /// [SymbolTable::par_intern_all], must not reorder or skip any. By
+    /// default, it returns false.
+    fn evicts(&self) -> bool {
+        false
+    }
+    /// [next_id] returns the id the next new [String] would be issued, if the
+    /// interner can tell in advance, so callers can check it fits before
+    /// interning. By default, it returns [None].
+    fn next_id(&self) -> Option<u64> {
+        None
+    }
+    /// [len] returns the number of distinct [String]s held by the interner.
+    fn len(&self) -> usize;
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// [stats] reports how much the interner is storing.
+    fn stats(&self) -> TableStats;
+    /// [heap_size] estimates the bytes the interner holds on the heap,
+    /// including spare capacity, which [stats] reports as
+    /// `estimated_heap_bytes`. By default, it only counts the bytes of the
+    /// strings returned by [strings].
+    fn heap_size(&self) -> usize {
+        self.strings().iter().map(|val| val.len()).sum()
+    }
+    /// [as_any] exposes the interner as [Any], so a [SymbolTable] can be
+    /// downcast to its concrete interner. Implementations return `self`.
+    fn as_any(&self) -> &dyn Any;
+}
+
+/// [issue_id] converts the position of a string which is about to be stored
+/// into its id, failing with [InternErr::IdSpaceExhausted] if the position
+/// lies beyond the ids a [SerialU64] can hold. Interners call it before
+/// storing anything, so a failed intern leaves them unchanged.
+pub(crate) fn issue_id(position: u64) -> Result<SerialU64<()>, InternErr> {
+    SerialU64::try_from(position).map_err(|_| InternErr::IdSpaceExhausted)
 }
 
 #[cfg(test)]
@@ -0,0 +1,263 @@
+use alloc::boxed::Box;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::any::{Any, TypeId};
+use core::cell::RefCell;
+
+use typed_ids::SerialU64;
+
+use super::{InternErr, Interner, TableStats};
+use crate::interner::issue_id;
+
+/// A [LazyInterner] is a read-through cache in front of an external store,
+/// such as a key-value database. It assigns ids to the strings it interns,
+/// and the store serves them back: on a cache miss, `resolve` fetches the
+/// string for an id through the resolver closure, and keeps it locally from
+/// then on. The store is never written to, so it is up to the caller to
+/// record each interned string under its id.
+///
+/// Looking a string up never goes to the store. The interner keeps its own
+/// index from each live string to its id, so a string is never issued a
+/// second id, even if the store can't produce it.
+///
+/// Ids are never reissued, not even after [clear], since the store may
+/// still hold strings under the old ones.
+pub struct LazyInterner {
+    resolver: Box<dyn Fn(SerialU64<()>) -> Option<String>>,
+    // The strings fetched or interned so far, by id. Interior mutability
+    // lets `resolve` fill the cache through a shared reference.
+    cache:    RefCell<BTreeMap<u64, String>>,
+    // The id of each live string.
+    index:    BTreeMap<String, u64>,
+    // The types interned under each live id.
+    typs:     BTreeMap<u64, BTreeSet<TypeId>>,
+    // The next id to issue. Id 0 is never issued.
+    next_id:  u64,
+}
+
+impl LazyInterner {
+    /// [new] constructs an empty interner which falls back to `resolver`
+    /// for any string it hasn't cached.
+    pub fn new(resolver: impl Fn(SerialU64<()>) -> Option<String> + 'static) -> Self {
+        Self {
+            resolver: Box::new(resolver),
+            cache:    RefCell::new(BTreeMap::new()),
+            index:    BTreeMap::new(),
+            typs:     BTreeMap::new(),
+            next_id:  1,
+        }
+    }
+
+    /// [clear_cache] drops every locally cached string, so later lookups
+    /// go back to the resolver. Ids and type associations are kept.
+    pub fn clear_cache(&self) {
+        self.cache.borrow_mut().clear();
+    }
+
+    /// [cached_len] returns the number of strings held in the local cache.
+    pub fn cached_len(&self) -> usize {
+        self.cache.borrow().len()
+    }
+
+    // Returns the string for a live id, from the cache if possible,
+    // otherwise from the resolver, caching whatever it returns.
+    fn fetch(&self, id: u64) -> Option<String> {
+        if !self.typs.contains_key(&id) {
+            return None;
+        }
+        if let Some(value) = self.cache.borrow().get(&id) {
+            return Some(value.clone());
+        }
+        let value = (self.resolver)(self.to_id(id))?;
+        self.cache.borrow_mut().insert(id, value.clone());
+        Some(value)
+    }
+
+    // Returns the live id holding this string, if any.
+    fn find(&self, val: &str) -> Option<u64> {
+        self.index.get(val).cloned()
+    }
+
+    fn to_id(&self, id: u64) -> SerialU64<()> {
+        SerialU64::try_from(id).unwrap()
+    }
+}
+
+impl Interner for LazyInterner {
+    fn intern(&mut self, val: String, typ: TypeId) -> Result<SerialU64<()>, InternErr> {
+        // • If some id already holds the string, add the type to it.
+        if let Some(id) = self.find(&val) {
+            self.typs.entry(id).or_default().insert(typ);
+            return Ok(self.to_id(id));
+        }
+        // • Otherwise, issue a new id, indexing and caching the string.
+        let issued = issue_id(self.next_id)?;
+        let id = self.next_id;
+        self.next_id += 1;
+        self.typs.entry(id).or_default().insert(typ);
+        self.index.insert(val.clone(), id);
+        self.cache.borrow_mut().insert(id, val);
+        Ok(issued)
+    }
+
+    fn resolve(&self, id: SerialU64<()>) -> Option<String> {
+        self.fetch(id.get())
+    }
+
+    fn get_interned(&self, val: String, typ: TypeId) -> Option<SerialU64<()>> {
+        self.get_interned_str(&val, typ)
+    }
+
+    fn get_interned_str(&self, val: &str, typ: TypeId) -> Option<SerialU64<()>> {
+        self.find(val)
+            .filter(|id| self.typs[id].contains(&typ))
+            .map(|id| self.to_id(id))
+    }
+
+    fn get_interned_any(&self, val: &str) -> Option<SerialU64<()>> {
+        self.find(val).map(|id| self.to_id(id))
+    }
+
+    fn entries(&self) -> Vec<(SerialU64<()>, String, Vec<TypeId>)> {
+        // Ids the resolver can't materialize are skipped.
+        self.typs
+            .iter()
+            .filter_map(|(id, typs)| {
+                let value = self.fetch(*id)?;
+                Some((self.to_id(*id), value, typs.iter().cloned().collect()))
+            })
+            .collect()
+    }
+
+    fn remove(&mut self, id: SerialU64<()>, typ: TypeId) -> bool {
+        let raw = id.get();
+        let typs = match self.typs.get_mut(&raw) {
+            Some(typs) => typs,
+            None => return false,
+        };
+        if !typs.remove(&typ) {
+            return false;
+        }
+        // • Once no types refer to the id, retire it.
+        if typs.is_empty() {
+            self.typs.remove(&raw);
+            self.index.retain(|_, held| *held != raw);
+            self.cache.borrow_mut().remove(&raw);
+        }
+        true
+    }
+
+    fn clear(&mut self) {
+        // The store may still hold strings under the old ids, so keep
+        // counting up rather than reissuing them.
+        self.typs.clear();
+        self.index.clear();
+        self.cache.borrow_mut().clear();
+    }
+
+    fn next_id(&self) -> Option<u64> {
+        Some(self.next_id)
+    }
+
+    fn len(&self) -> usize {
+        self.typs.len()
+    }
+
+    fn stats(&self) -> TableStats {
+        TableStats {
+            num_entries:           self.len(),
+            // Only the indexed and cached strings are stored locally.
+            total_bytes_stored:    self.index.keys().map(|value| value.len()).sum::<usize>()
+                + self
+                    .cache
+                    .borrow()
+                    .values()
+                    .map(|value| value.len())
+                    .sum::<usize>(),
+            num_type_associations: self.typs.values().map(|typs| typs.len()).sum(),
+            estimated_heap_bytes:  self.heap_size(),
+        }
+    }
+
+    fn heap_size(&self) -> usize {
+        // Don't go to the resolver; only count what is held locally.
+        let index: usize = self.index.keys().map(|value| value.capacity()).sum();
+        let cache: usize = self
+            .cache
+            .borrow()
+            .values()
+            .map(|value| value.capacity())
+            .sum();
+        index + cache
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, RefCell};
+    use std::collections::BTreeMap;
+    use std::rc::Rc;
+
+    use super::LazyInterner;
+    use crate::{ResolutionErr, Symbol, SymbolTable};
+
+    #[test]
+    fn resolves_through_the_store() {
+        // • An in-memory store, which counts how often it's read.
+        let store: Rc<RefCell<BTreeMap<u64, String>>> = Default::default();
+        let reads = Rc::new(Cell::new(0));
+        let mut table = {
+            let store = store.clone();
+            let reads = reads.clone();
+            SymbolTable::with_lazy_interner(move |id| {
+                reads.set(reads.get() + 1);
+                store.borrow().get(&id.get()).cloned()
+            })
+        };
+        let toad: Symbol<String> = table.intern(&"toad".to_owned());
+        let frog: Symbol<String> = table.intern(&"frog".to_owned());
+        store.borrow_mut().insert(toad.raw(), "toad".to_owned());
+        // • Freshly interned strings are served from the cache.
+        assert_eq!(table.resolve(&toad), Ok("toad".to_owned()));
+        assert_eq!(reads.get(), 0);
+        // • Once evicted, they're fetched from the store, then cached again.
+        table.interner_ref::<LazyInterner>().unwrap().clear_cache();
+        assert_eq!(table.resolve(&toad), Ok("toad".to_owned()));
+        assert_eq!(table.resolve(&toad), Ok("toad".to_owned()));
+        assert_eq!(reads.get(), 1);
+        // • The store never recorded frog, so it can't be materialized.
+        assert_eq!(table.resolve(&frog), Err(ResolutionErr::UnknownIdErr));
+        let again: Symbol<String> = table.intern(&"toad".to_owned());
+        assert_eq!(again, toad);
+        assert_eq!(table.len(), 2);
+    }
+
+    #[test]
+    fn lookups_skip_the_store() {
+        let reads = Rc::new(Cell::new(0));
+        let mut table = {
+            let reads = reads.clone();
+            SymbolTable::with_lazy_interner(move |_| {
+                reads.set(reads.get() + 1);
+                None
+            })
+        };
+        let toad: Symbol<String> = table.intern(&"toad".to_owned());
+        let _: Symbol<String> = table.intern(&"frog".to_owned());
+        table.interner_ref::<LazyInterner>().unwrap().clear_cache();
+        // • The store can't produce toad, but it keeps its id.
+        assert_eq!(table.intern(&"toad".to_owned()), toad);
+        assert!(table.has_interned::<String, _>("toad"));
+        assert_eq!(reads.get(), 0);
+        assert_eq!(table.len(), 2);
+        // • After a clear, ids keep counting up.
+        table.clear();
+        let newt: Symbol<String> = table.intern(&"newt".to_owned());
+        assert_eq!(newt.raw(), 3);
+    }
+}
@@ -8,7 +8,8 @@
 //! Into<String>, you can get back a Symbol<Address> and a Symbol<Username>.
 //! These two [Symbol] types will share the same store and any benefits of
 //! compression, while ensuring you don't mix up one Symbol for another, as is
-//! easy with strings: ```text
+//! easy with strings:
+//! ```text
 //! fn foo(address: String, username: String);
 //! foo(my_username, my_address); // This is well-typed, but is logically
 //! erronious, because the parameters were mixed up.
@@ -16,21 +17,94 @@
 //! // This formulation would produce an type error when you accidently
 //! // swap the argument positions.
 //! ```
-use array::ArrayInterner;
-pub use errors::{ResolutionErr, TableMismatchErr};
+//!
+//! The crate is `no_std` compatible when the default `std` feature is
+//! disabled, requiring only `alloc`. Without `std`, the
+//! [ConcurrentSymbolTable] is unavailable. Without the default `diagnostics`
+//! feature, [TableMismatchErr] is not a miette `Diagnostic`, and its `Debug`
+//! output is plain text.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+pub use array::ArrayInterner;
+pub use binary::{BinaryInterner, Bytes, InternableBytes};
+pub use bounded::BoundedInterner;
+pub use buffer::BufferInterner;
+pub use builder::SymbolTableBuilder;
+pub use cached_symbol::CachedSymbol;
+pub use case_insensitive::CaseInsensitiveInterner;
+#[cfg(feature = "std")]
+pub use concurrent_symbol::ConcurrentSymbol;
+#[cfg(feature = "std")]
+pub use concurrent_table::ConcurrentSymbolTable;
+pub use errors::{DecodeErr, InternErr, ResolutionErr, TableMismatchErr};
 pub use flavor::InternerFlavor;
+pub use frozen_table::FrozenSymbolTable;
+pub use fx_hash::FxHashInterner;
 pub use internable::Internable;
 pub use interner::Interner;
+pub use lazy::LazyInterner;
+pub use radix::RadixInterner;
+pub use rc_str::RcStrInterner;
+pub use remap::SymbolRemap;
+pub use snapshot::TableSnapshot;
+pub use sorted::SortedInterner;
+pub use stats::TableStats;
+pub use suffix::SuffixInterner;
+pub use suffix_index::SuffixIndexInterner;
 use symbol::Resolvable;
 pub use symbol::Symbol;
+pub use symbol32::Symbol32;
+pub use symbol_byte_iterator::SymbolByteIterator;
 pub use symbol_iterator::SymbolIterator;
+pub use symbol_str::SymbolStr;
 pub use table::SymbolTable;
+pub use table32::SymbolTable32;
 
 mod array;
+mod binary;
+mod bounded;
+mod buffer;
+mod builder;
+mod cached_symbol;
+mod case_insensitive;
+mod cell_store;
+#[cfg(feature = "std")]
+mod concurrent_symbol;
+#[cfg(feature = "std")]
+mod concurrent_table;
+#[cfg(feature = "std")]
+mod encoding;
 mod errors;
 mod flavor;
+mod frozen_table;
+mod fx_hash;
 mod internable;
 mod interner;
+mod lazy;
+mod macros;
+mod radix;
+mod rc_str;
+mod remap;
+mod snapshot;
+mod sorted;
+mod stats;
+mod suffix;
+mod suffix_index;
 mod symbol;
+mod symbol32;
+mod symbol_byte_iterator;
 mod symbol_iterator;
+mod symbol_str;
 mod table;
+mod table32;
+#[cfg(feature = "std")]
+mod type_registry;
+
+// Items used by the exported macros, which can't assume the
+// calling crate has access to alloc.
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::string::String;
+}
@@ -0,0 +1,90 @@
+/// [define_interned_type] declares a newtype around a [String] which is
+/// [Internable], for telling apart strings from different sources. Each
+/// generated type produces its own kind of [Symbol], so a `Symbol<Address>`
+/// can't be passed where a `Symbol<Username>` is expected, even though both
+/// can share one [SymbolTable].
+///
+/// ```
+/// use symboltable::{define_interned_type, InternerFlavor, Symbol, SymbolTable};
+///
+/// define_interned_type!(Address);
+/// define_interned_type!(
+///     /// The name a user logs in with.
+///     pub Username
+/// );
+///
+/// let mut table = SymbolTable::new(InternerFlavor::Array);
+/// let home: Symbol<Address> = table.intern(&Address::from("1 Main St".to_owned()));
+/// let bob: Symbol<Username> = table.intern(&Username::from("bob".to_owned()));
+/// assert_eq!(table.resolve(&bob).unwrap().as_ref(), "bob");
+/// ```
+#[macro_export]
+macro_rules! define_interned_type {
+    ($(#[$meta:meta])* $vis:vis $name:ident) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        $vis struct $name(pub $crate::__private::String);
+
+        impl ::core::convert::AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl ::core::convert::From<$crate::__private::String> for $name {
+            fn from(val: $crate::__private::String) -> Self {
+                Self(val)
+            }
+        }
+    };
+}
+
+/// [intern_as] interns one string as each of the listed types, returning a
+/// tuple with a [Symbol] of each type, in order. The string is only stored
+/// once, so every Symbol shares an id. It generalizes [SymbolTable::intern_as]
+/// to any number of types.
+///
+/// ```
+/// use symboltable::{intern_as, InternerFlavor, SymbolTable};
+///
+/// let mut table = SymbolTable::new(InternerFlavor::Array);
+/// let (text, boxed, name) = intern_as!(table, "bob", String, Box<str>, String);
+/// assert_eq!(text, name);
+/// assert_eq!(text.raw(), boxed.raw());
+/// ```
+#[macro_export]
+macro_rules! intern_as {
+    ($table:expr, $val:expr, $($typ:ty),+ $(,)?) => {{
+        let table: &mut $crate::SymbolTable = &mut $table;
+        let val: &str = $val;
+        ($(table.intern_str::<$typ>(val),)+)
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use static_assertions::{assert_impl_all, assert_type_ne_all};
+
+    use crate::{Internable, InternerFlavor, Symbol, SymbolTable};
+
+    define_interned_type!(Address);
+    define_interned_type!(
+        /// A documented, public newtype.
+        pub Username
+    );
+
+    assert_impl_all!(Address: Internable);
+    assert_impl_all!(Username: Internable);
+    assert_type_ne_all!(Symbol<Address>, Symbol<Username>);
+
+    #[test]
+    fn generated_types_intern() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let home = table.intern(&Address::from("bob".to_owned()));
+        let bob = table.intern(&Username("bob".to_owned()));
+        assert_eq!(home.raw(), bob.raw());
+        assert_eq!(table.resolve(&home), Ok(Address("bob".to_owned())));
+        assert_eq!(table.resolve(&bob), Ok(Username("bob".to_owned())));
+        assert!(!table.has_interned::<Username, _>("1 Main St"));
+    }
+}
@@ -0,0 +1,413 @@
+use alloc::collections::BTreeSet;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::any::{Any, TypeId};
+use core::mem::size_of;
+
+use typed_ids::SerialU64;
+
+use super::{InternErr, Interner, TableStats};
+use crate::interner::issue_id;
+use crate::stats::type_set_bytes;
+
+/// A [RadixInterner] stores [Symbol]s in a radix trie, so strings which
+/// share a prefix (like file paths) only store that prefix once.
+/// It performs `intern` in O(k) for a string of length k, and `resolve`
+/// in O(d), where d is the depth of the string's node in the trie.
+#[derive(Clone, Debug)]
+pub struct RadixInterner {
+    // The nodes of the trie. Slot[0] is the root, which holds the empty
+    // prefix that every string shares.
+    nodes:   Vec<RadixNode>,
+    // Maps each id to the node terminating its string. Slot[0] is the
+    // sentinel, which points at the root.
+    ids:     Vec<usize>,
+    // The number of ids which have been removed.
+    removed: usize,
+}
+
+impl RadixInterner {
+    pub fn new() -> Self {
+        let root = RadixNode::new(String::from(""), 0);
+        Self {
+            nodes:   vec![root],
+            ids:     vec![0],
+            removed: 0,
+        }
+    }
+
+    // Returns the node terminating `val`, if the trie contains it.
+    fn find(&self, val: &str) -> Option<usize> {
+        let mut node = 0;
+        let mut rest = val;
+        while !rest.is_empty() {
+            let child = self.child_starting_with(node, rest)?;
+            rest = rest.strip_prefix(self.nodes[child].label.as_str())?;
+            node = child;
+        }
+        Some(node)
+    }
+
+    // Returns the node terminating `val`, adding and splitting
+    // nodes as needed to make room for it.
+    fn insert(&mut self, val: &str) -> usize {
+        let mut node = 0;
+        let mut rest = val;
+        while !rest.is_empty() {
+            let child = match self.child_starting_with(node, rest) {
+                Some(child) => child,
+                // • No edge shares a prefix with the rest of the string,
+                //   so it gets a leaf of its own.
+                None => return self.add_child(node, rest.to_string()),
+            };
+            let common = common_prefix_len(&self.nodes[child].label, rest);
+            // • If the edge is only partially matched, split it so
+            //   the shared part becomes its own node.
+            if common < self.nodes[child].label.len() {
+                self.split(child, common);
+                node = self.nodes[child].parent;
+            } else {
+                node = child;
+            }
+            rest = &rest[common..];
+        }
+        node
+    }
+
+    // Finds the child of `node` whose label begins with the same char as `rest`.
+    // In a radix trie, there is at most one such child.
+    fn child_starting_with(&self, node: usize, rest: &str) -> Option<usize> {
+        let first = rest.chars().next()?;
+        self.nodes[node]
+            .children
+            .iter()
+            .cloned()
+            .find(|child| self.nodes[*child].label.starts_with(first))
+    }
+
+    fn add_child(&mut self, parent: usize, label: String) -> usize {
+        let child = self.nodes.len();
+        self.nodes.push(RadixNode::new(label, parent));
+        self.nodes[parent].children.push(child);
+        child
+    }
+
+    // Splits the label of `node` at byte offset `at`, inserting a new node
+    // holding the front half between `node` and its parent. The index of
+    // `node` is unchanged, so ids pointing at it stay valid.
+    fn split(&mut self, node: usize, at: usize) {
+        let parent = self.nodes[node].parent;
+        let suffix = self.nodes[node].label.split_off(at);
+        let prefix = core::mem::replace(&mut self.nodes[node].label, suffix);
+        let middle = self.nodes.len();
+        let mut middle_node = RadixNode::new(prefix, parent);
+        middle_node.children.push(node);
+        self.nodes.push(middle_node);
+        self.nodes[node].parent = middle;
+        for child in self.nodes[parent].children.iter_mut() {
+            if *child == node {
+                *child = middle;
+            }
+        }
+    }
+
+    // Returns the node holding this id, unless the id was removed.
+    // A removed node may be reissued a new id if its string is interned
+    // again, so the node must still point back at this id to be live.
+    fn live_node(&self, id: usize) -> Option<usize> {
+        let node = *self.ids.get(id)?;
+        match self.nodes[node].id {
+            Some(current) if current == id => Some(node),
+            _ => None,
+        }
+    }
+
+    fn to_id(&self, position: usize) -> SerialU64<()> {
+        SerialU64::try_from(position as u64).unwrap()
+    }
+}
+
+impl Default for RadixInterner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Debug)]
+struct RadixNode {
+    // The characters on the edge leading into this node.
+    label:    String,
+    parent:   usize,
+    children: Vec<usize>,
+    // If a string terminates at this node, this is its id.
+    id:       Option<usize>,
+    typs:     BTreeSet<TypeId>,
+}
+
+impl RadixNode {
+    fn new(label: String, parent: usize) -> Self {
+        Self {
+            label,
+            parent,
+            children: Vec::new(),
+            id: None,
+            typs: Default::default(),
+        }
+    }
+}
+
+// Returns the length in bytes of the longest common prefix of `a` and `b`,
+// which always falls on a char boundary of both.
+fn common_prefix_len(a: &str, b: &str) -> usize {
+    a.chars()
+        .zip(b.chars())
+        .take_while(|(x, y)| x == y)
+        .map(|(x, _)| x.len_utf8())
+        .sum()
+}
+
+impl Interner for RadixInterner {
+    fn intern(&mut self, val: String, typ: TypeId) -> Result<SerialU64<()>, InternErr> {
+        // • A new string needs a new id, so make sure one is left
+        //   before the trie is changed.
+        let is_stored = self
+            .find(&val)
+            .is_some_and(|node| self.nodes[node].id.is_some());
+        if !is_stored {
+            issue_id(self.ids.len() as u64)?;
+        }
+        // • Walk the trie, adding the string if it's missing.
+        let node = self.insert(&val);
+        // • If the string didn't already terminate at this node,
+        //   issue it a new id.
+        let id = match self.nodes[node].id {
+            Some(id) => id,
+            None => {
+                let id = self.ids.len();
+                self.ids.push(node);
+                self.nodes[node].id = Some(id);
+                id
+            }
+        };
+        self.nodes[node].typs.insert(typ);
+        Ok(self.to_id(id))
+    }
+
+    fn resolve(&self, id: SerialU64<()>) -> Option<String> {
+        // Collect the labels on the path from the node to the root,
+        // then stitch them together in reverse.
+        let mut node = self.live_node(id.get() as usize)?;
+        let mut labels = Vec::new();
+        while node != 0 {
+            labels.push(self.nodes[node].label.as_str());
+            node = self.nodes[node].parent;
+        }
+        Some(labels.into_iter().rev().collect())
+    }
+
+    fn get_interned(&self, val: String, typ: TypeId) -> Option<SerialU64<()>> {
+        self.get_interned_str(&val, typ)
+    }
+
+    fn get_interned_str(&self, val: &str, typ: TypeId) -> Option<SerialU64<()>> {
+        let node = &self.nodes[self.find(val)?];
+        node.id
+            .filter(|_| node.typs.contains(&typ))
+            .map(|id| self.to_id(id))
+    }
+
+    fn get_interned_any(&self, val: &str) -> Option<SerialU64<()>> {
+        self.nodes[self.find(val)?].id.map(|id| self.to_id(id))
+    }
+
+    fn entries(&self) -> Vec<(SerialU64<()>, String, Vec<TypeId>)> {
+        // Skip the sentinel, which was never interned by the user,
+        // along with any removed ids.
+        (1..self.ids.len())
+            .filter_map(|id| {
+                let node = self.live_node(id)?;
+                let value = self.resolve(self.to_id(id))?;
+                let typs = self.nodes[node].typs.iter().cloned().collect();
+                Some((self.to_id(id), value, typs))
+            })
+            .collect()
+    }
+
+    fn types_for(&self, val: &str) -> Vec<TypeId> {
+        // A node only holds types while a string terminates there.
+        self.find(val)
+            .map(|node| self.nodes[node].typs.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    fn remove(&mut self, id: SerialU64<()>, typ: TypeId) -> bool {
+        let id = id.get() as usize;
+        // The sentinel is never removed.
+        let node = match self.live_node(id) {
+            Some(node) if id != 0 => node,
+            _ => return false,
+        };
+        if !self.nodes[node].typs.remove(&typ) {
+            return false;
+        }
+        // • Once no types refer to the string, retire its id. The trie
+        //   keeps its nodes, since other strings may share them.
+        if self.nodes[node].typs.is_empty() {
+            self.nodes[node].id = None;
+            self.removed += 1;
+        }
+        true
+    }
+
+    fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        // Each new string adds at most a leaf and a split node.
+        self.nodes.reserve(2 * additional);
+        self.ids.reserve(additional);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.nodes.shrink_to_fit();
+        self.ids.shrink_to_fit();
+    }
+
+    fn next_id(&self) -> Option<u64> {
+        Some(self.ids.len() as u64)
+    }
+
+    fn len(&self) -> usize {
+        // Don't count the sentinel or removed ids.
+        self.ids.len() - 1 - self.removed
+    }
+
+    fn stats(&self) -> TableStats {
+        TableStats {
+            num_entries:           self.len(),
+            total_bytes_stored:    self.nodes.iter().map(|node| node.label.len()).sum(),
+            num_type_associations: self.nodes.iter().map(|node| node.typs.len()).sum(),
+            estimated_heap_bytes:  self.heap_size(),
+        }
+    }
+
+    fn heap_size(&self) -> usize {
+        let nodes = self.nodes.capacity() * size_of::<RadixNode>();
+        let ids = self.ids.capacity() * size_of::<usize>();
+        let contents: usize = self
+            .nodes
+            .iter()
+            .map(|node| {
+                node.label.capacity()
+                    + node.children.capacity() * size_of::<usize>()
+                    + type_set_bytes(&node.typs)
+            })
+            .sum();
+        nodes + ids + contents
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+
+    use super::RadixInterner;
+    use crate::array::ArrayInterner;
+    use crate::Interner;
+
+    const PATHS: [&str; 8] = [
+        "/usr/local/lib/libfoo.so",
+        "/usr/local/lib/libbar.so",
+        "/usr/local/include/foo.h",
+        "/usr/local/include/bar.h",
+        "/usr/lib/libc.so",
+        "/usr/lib/libm.so",
+        "/usr",
+        "/home/user/.config",
+    ];
+
+    #[test]
+    fn round_trip() {
+        let typ = TypeId::of::<String>();
+        let mut interner = RadixInterner::new();
+        let ids: Vec<_> = PATHS
+            .iter()
+            .map(|path| interner.intern(path.to_string(), typ).unwrap())
+            .collect();
+        for (path, id) in PATHS.iter().zip(&ids) {
+            assert_eq!(interner.resolve(*id).as_deref(), Some(*path));
+            assert_eq!(interner.get_interned(path.to_string(), typ), Some(*id));
+            assert_eq!(interner.intern(path.to_string(), typ).unwrap(), *id);
+        }
+        assert_eq!(interner.len(), PATHS.len());
+        assert_eq!(interner.get_interned("/usr/local".to_string(), typ), None);
+        assert_eq!(interner.get_interned("/usr/lib".to_string(), typ), None);
+    }
+
+    #[test]
+    fn splits_keep_ids_stable() {
+        let typ = TypeId::of::<String>();
+        let mut interner = RadixInterner::new();
+        let toast = interner.intern("toast".to_string(), typ).unwrap();
+        let toad = interner.intern("toad".to_string(), typ).unwrap();
+        let to = interner.intern("to".to_string(), typ).unwrap();
+        let tea = interner.intern("tea".to_string(), typ).unwrap();
+        let cafe = interner.intern("café".to_string(), typ).unwrap();
+        let cafes = interner.intern("cafés".to_string(), typ).unwrap();
+        assert_eq!(interner.resolve(toast).as_deref(), Some("toast"));
+        assert_eq!(interner.resolve(toad).as_deref(), Some("toad"));
+        assert_eq!(interner.resolve(to).as_deref(), Some("to"));
+        assert_eq!(interner.resolve(tea).as_deref(), Some("tea"));
+        assert_eq!(interner.resolve(cafe).as_deref(), Some("café"));
+        assert_eq!(interner.resolve(cafes).as_deref(), Some("cafés"));
+    }
+
+    #[test]
+    fn removed_ids_stay_retired() {
+        let typ = TypeId::of::<String>();
+        let mut interner = RadixInterner::new();
+        let toad = interner.intern("toad".to_string(), typ).unwrap();
+        let toast = interner.intern("toast".to_string(), typ).unwrap();
+        assert!(interner.remove(toad, typ));
+        assert!(!interner.remove(toad, typ));
+        assert_eq!(interner.resolve(toad), None);
+        assert_eq!(interner.resolve(toast).as_deref(), Some("toast"));
+        assert_eq!(interner.len(), 1);
+        let again = interner.intern("toad".to_string(), typ).unwrap();
+        assert_ne!(again, toad);
+        assert_eq!(interner.resolve(toad), None);
+        assert_eq!(interner.resolve(again).as_deref(), Some("toad"));
+    }
+
+    #[test]
+    fn type_is_checked() {
+        let mut interner = RadixInterner::new();
+        interner
+            .intern("toad".to_string(), TypeId::of::<String>())
+            .unwrap();
+        assert!(interner
+            .get_interned("toad".to_string(), TypeId::of::<u8>())
+            .is_none());
+    }
+
+    #[test]
+    fn stores_less_than_array() {
+        let typ = TypeId::of::<String>();
+        let mut radix = RadixInterner::new();
+        let mut array = ArrayInterner::new();
+        for path in PATHS {
+            radix.intern(path.to_string(), typ).unwrap();
+            array.intern(path.to_string(), typ).unwrap();
+        }
+        let radix_stats = radix.stats();
+        let array_stats = array.stats();
+        assert_eq!(radix_stats.num_entries, array_stats.num_entries);
+        assert!(radix_stats.total_bytes_stored < array_stats.total_bytes_stored);
+    }
+}
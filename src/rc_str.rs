@@ -0,0 +1,170 @@
+use alloc::borrow::Cow;
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::any::{Any, TypeId};
+use core::mem::size_of;
+
+use typed_ids::SerialU64;
+
+use super::{InternErr, Interner, TableStats};
+use crate::cell_store::{to_id, CellStore};
+
+/// An [RcStrInterner] is an [ArrayInterner] which stores each string as an
+/// [Rc<str>]. [resolve_rc] hands out clones of the stored [Rc], so callers
+/// which accept an [Rc<str>] share the interner's allocation instead of
+/// receiving a fresh [String]. It performs `intern` in O(n), and `resolve` in
+/// O(1).
+///
+/// Since [Rc] isn't [Send], there is no [InternerFlavor] for this interner.
+/// Construct a table over it with [SymbolTable::from].
+#[derive(Clone, Debug)]
+pub struct RcStrInterner {
+    store: CellStore<Rc<str>>,
+}
+
+impl RcStrInterner {
+    pub fn new() -> Self {
+        Self {
+            store: CellStore::new(),
+        }
+    }
+
+    fn position(&self, val: &str) -> Option<usize> {
+        self.store.position(|value| &**value == val)
+    }
+}
+
+impl Default for RcStrInterner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Interner for RcStrInterner {
+    fn intern(&mut self, val: String, typ: TypeId) -> Result<SerialU64<()>, InternErr> {
+        // • If the string is already stored, add the type to its cell.
+        if let Some(position) = self.position(&val) {
+            self.store.add_type(position, typ);
+            return Ok(to_id(position));
+        }
+        // • Otherwise, append a new cell holding the string.
+        self.store.push(Rc::from(val), typ).map(to_id)
+    }
+
+    fn resolve(&self, id: SerialU64<()>) -> Option<String> {
+        self.resolve_ref(id).map(Cow::into_owned)
+    }
+
+    fn resolve_ref(&self, id: SerialU64<()>) -> Option<Cow<'_, str>> {
+        let value = self.store.get(id.get() as usize)?;
+        Some(Cow::Borrowed(&**value))
+    }
+
+    fn resolve_rc(&self, id: SerialU64<()>) -> Option<Rc<str>> {
+        self.store.get(id.get() as usize).cloned()
+    }
+
+    fn get_interned(&self, val: String, typ: TypeId) -> Option<SerialU64<()>> {
+        self.get_interned_str(&val, typ)
+    }
+
+    fn get_interned_str(&self, val: &str, typ: TypeId) -> Option<SerialU64<()>> {
+        self.position(val)
+            .filter(|position| self.store.has_type(*position, &typ))
+            .map(to_id)
+    }
+
+    fn get_interned_any(&self, val: &str) -> Option<SerialU64<()>> {
+        self.position(val).map(to_id)
+    }
+
+    fn entries(&self) -> Vec<(SerialU64<()>, String, Vec<TypeId>)> {
+        self.store.entries(|value| String::from(&**value))
+    }
+
+    fn remove(&mut self, id: SerialU64<()>, typ: TypeId) -> bool {
+        // Once no types refer to the string, the store lets go of it. Any
+        // clones handed out by `resolve_rc` keep the string alive.
+        self.store.remove(id.get() as usize, &typ)
+    }
+
+    fn clear(&mut self) {
+        self.store.clear();
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.store.reserve(additional);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.store.shrink_to_fit();
+    }
+
+    fn next_id(&self) -> Option<u64> {
+        Some(self.store.next_id())
+    }
+
+    fn len(&self) -> usize {
+        self.store.len()
+    }
+
+    fn stats(&self) -> TableStats {
+        TableStats {
+            num_entries:           self.len(),
+            total_bytes_stored:    self.store.iter().map(|(_, value, _)| value.len()).sum(),
+            num_type_associations: self.store.num_type_associations(),
+            estimated_heap_bytes:  self.heap_size(),
+        }
+    }
+
+    fn heap_size(&self) -> usize {
+        // Each Rc<str> allocation also holds its strong and weak counts.
+        self.store
+            .heap_size(|value| value.len() + 2 * size_of::<usize>())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::ptr;
+    use std::rc::Rc;
+
+    use super::RcStrInterner;
+    use crate::{Interner, InternerFlavor, Symbol, SymbolTable};
+
+    #[test]
+    fn resolves_share_one_allocation() {
+        let mut table = SymbolTable::from(RcStrInterner::new());
+        let toad: Symbol<String> = table.intern(&"toad".to_owned());
+        let first = table.resolve_rc(&toad).unwrap();
+        let second = table.resolve_rc(&toad).unwrap();
+        assert!(Rc::ptr_eq(&first, &second));
+        assert_eq!(&*first, "toad");
+        assert_eq!(table.resolve(&toad), Ok("toad".to_owned()));
+        // • Borrowing the string doesn't copy it either.
+        let interner = table.interner_ref::<RcStrInterner>().unwrap();
+        let borrowed = interner.resolve_ref(toad.erase_type()).unwrap();
+        assert!(matches!(borrowed, Cow::Borrowed(value) if ptr::eq(value, &*first)));
+        drop(interner);
+        // • The string outlives its removal from the table.
+        assert!(table.remove(&toad));
+        assert!(table.resolve_rc(&toad).is_err());
+        assert_eq!(&*first, "toad");
+    }
+
+    #[test]
+    fn other_interners_fall_back() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let toad: Symbol<String> = table.intern(&"toad".to_owned());
+        let first = table.resolve_rc(&toad).unwrap();
+        let second = table.resolve_rc(&toad).unwrap();
+        assert_eq!(first, second);
+        assert!(!Rc::ptr_eq(&first, &second));
+    }
+}
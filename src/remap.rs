@@ -0,0 +1,70 @@
+use alloc::collections::BTreeMap;
+use core::ptr;
+
+use typed_ids::SerialU64;
+
+use super::{Internable, Resolvable, Symbol, SymbolTable};
+
+/// A [SymbolRemap] records where each string of one [SymbolTable] landed
+/// after being copied into another, as returned by [SymbolTable::merge], or
+/// after a table was renumbered by [SymbolTable::compact]. Since the ids
+/// differ, a [Symbol] from the source table must be translated before it
+/// can be used with the target. Only Symbols the source table issued before
+/// the remap was made can be translated.
+pub struct SymbolRemap {
+    // The table Symbols are translated from. Holding it keeps the address
+    // Symbols are checked against from being reused by another table.
+    source:     SymbolTable,
+    // The table translated Symbols are bound to.
+    target:     SymbolTable,
+    // Maps each id in the source table to its id in the target table.
+    ids:        BTreeMap<u64, u64>,
+    // The source table's generation when the remap was made.
+    generation: u64,
+}
+
+impl SymbolRemap {
+    pub(crate) fn new(source: SymbolTable, target: SymbolTable, ids: BTreeMap<u64, u64>) -> Self {
+        Self {
+            generation: source.generation(),
+            source,
+            target,
+            ids,
+        }
+    }
+
+    /// [translate] converts a [Symbol] from the source table into the
+    /// Symbol for the same string in the target table.
+    ///
+    /// # Panics
+    /// If the Symbol didn't come from the source table, or its string
+    /// wasn't present in the source table when the remap was made.
+    pub fn translate<T: Internable + 'static>(&self, old: Symbol<T>) -> Symbol<T> {
+        self.try_translate(&old)
+            .expect("the Symbol was not in the source table of this remap")
+    }
+
+    /// [try_translate] is like [translate], but returns [None] rather than
+    /// panicking when the Symbol can't be translated.
+    pub fn try_translate<T: Internable + 'static>(&self, old: &Symbol<T>) -> Option<Symbol<T>> {
+        if !ptr::addr_eq(self.source.addr(), old.origin()) {
+            return None;
+        }
+        // • A Symbol issued since the remap was made has a new-style id.
+        if old.generation() != self.generation {
+            return None;
+        }
+        let new_id = self.ids.get(&old.id().get())?;
+        let id = SerialU64::<T>::try_from(*new_id).ok()?;
+        Some(Symbol::new(id, self.target.clone()))
+    }
+
+    /// [len] returns the number of ids which can be translated.
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+}
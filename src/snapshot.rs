@@ -0,0 +1,143 @@
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::any::TypeId;
+
+use typed_ids::SerialU64;
+
+use super::{Internable, ResolutionErr, SymbolTable};
+use crate::errors::parse_resolved;
+
+/// A [TableSnapshot] is a read-only copy of a [SymbolTable] at a point in
+/// time, made by [SymbolTable::snapshot]. Unlike the table, it is [Send] and
+/// [Sync], and cloning it is cheap, so it can be handed to other threads
+/// while the original keeps interning. Later changes to the original are not
+/// seen by the snapshot.
+///
+/// Since a [Symbol] can't cross threads, the snapshot works in terms of the
+/// ids returned by [Symbol::id].
+#[derive(Clone)]
+pub struct TableSnapshot {
+    // Sorted by id. Only live entries are kept, since the ids of interners
+    // which evict, like the bounded interner, run far past their len.
+    entries: Arc<[SnapshotEntry]>,
+}
+
+struct SnapshotEntry {
+    id:    u64,
+    value: String,
+    typs:  Vec<TypeId>,
+}
+
+impl TableSnapshot {
+    pub(crate) fn new(table: &SymbolTable) -> Self {
+        let mut entries: Vec<SnapshotEntry> = table
+            .interner()
+            .entries()
+            .into_iter()
+            .map(|(id, value, typs)| SnapshotEntry {
+                id: id.get(),
+                value,
+                typs,
+            })
+            .collect();
+        entries.sort_unstable_by_key(|entry| entry.id);
+        Self {
+            entries: entries.into(),
+        }
+    }
+
+    fn entry(&self, id: u64) -> Option<&SnapshotEntry> {
+        let position = self
+            .entries
+            .binary_search_by_key(&id, |entry| entry.id)
+            .ok()?;
+        Some(&self.entries[position])
+    }
+
+    /// [resolve] returns the object stored under the id when the snapshot
+    /// was taken. It fails if the id was unknown to the table at that time,
+    /// or the string can't be parsed back into a `T`.
+    pub fn resolve<T: Internable + 'static>(
+        &self,
+        id: SerialU64<T>,
+    ) -> Result<T, ResolutionErr<T>> {
+        let resolution = self
+            .entry(id.get())
+            .map(|entry| entry.value.as_str())
+            .ok_or(ResolutionErr::UnknownIdErr)?;
+        parse_resolved(resolution)
+    }
+
+    pub fn get_interned<T: Internable + 'static, S: AsRef<str>>(
+        &self,
+        val: S,
+    ) -> Option<SerialU64<T>> {
+        let typ_id = TypeId::of::<T>();
+        let entry = self
+            .entries
+            .iter()
+            .find(|entry| entry.value == val.as_ref() && entry.typs.contains(&typ_id))?;
+        SerialU64::try_from(entry.id).ok()
+    }
+
+    pub fn has_interned<T: Internable + 'static, S: AsRef<str>>(&self, val: S) -> bool {
+        self.get_interned::<T, S>(val).is_some()
+    }
+
+    /// [len] returns the number of distinct strings in the snapshot.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::TableSnapshot;
+    use crate::{InternerFlavor, Symbol, SymbolTable};
+
+    #[test]
+    fn later_interns_are_unseen() {
+        let mut table = SymbolTable::new(InternerFlavor::Radix);
+        let frog: Symbol<String> = table.intern(&"frog".to_owned());
+        let snapshot = table.snapshot();
+        let _toad: Symbol<String> = table.intern(&"toad".to_owned());
+        assert_eq!(snapshot.len(), 1);
+        assert_eq!(table.len(), 2);
+        assert!(!snapshot.has_interned::<String, _>("toad"));
+        assert!(!snapshot.has_interned::<Box<str>, _>("frog"));
+        // • The snapshot can be read from another thread.
+        let id = frog.id();
+        let handle = thread::spawn(move || snapshot.resolve(id).ok());
+        assert_eq!(handle.join().unwrap(), Some("frog".to_owned()));
+    }
+
+    #[test]
+    fn snapshot_is_send_and_sync() {
+        fn assert_send_sync<S: Send + Sync>() {}
+        assert_send_sync::<TableSnapshot>();
+    }
+
+    #[test]
+    fn evicting_tables_snapshot_their_live_entries() {
+        let mut table = SymbolTable::new(InternerFlavor::Bounded(2));
+        let syms: Vec<Symbol<String>> = (0..1000)
+            .map(|i| table.intern_str(&i.to_string()))
+            .collect();
+        let snapshot = table.snapshot();
+        assert_eq!(snapshot.entries.len(), 2);
+        assert_eq!(snapshot.resolve(syms[999].id()), Ok("999".to_owned()));
+        assert_eq!(snapshot.resolve(syms[998].id()), Ok("998".to_owned()));
+        assert!(snapshot.resolve(syms[0].id()).is_err());
+        assert_eq!(
+            snapshot.get_interned::<String, _>("999"),
+            Some(syms[999].id())
+        );
+    }
+}
@@ -0,0 +1,230 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::any::{Any, TypeId};
+use core::mem::size_of;
+
+use typed_ids::SerialU64;
+
+use super::{InternErr, Interner, TableStats};
+use crate::cell_store::{to_id, CellStore};
+
+/// A [SortedInterner] keeps an index of its strings in sorted order, so
+/// looking a string up is a binary search rather than a scan. The strings
+/// themselves are stored by id, so `resolve` stays O(1), and inserting a
+/// string never changes the ids of the others. It suits tables which are
+/// built once and then queried heavily: `get_interned` is O(log n), while
+/// `intern` is O(n) for a new string, since the index has to make room.
+#[derive(Clone, Debug)]
+pub struct SortedInterner {
+    store: CellStore<String>,
+    // The ids of the live strings, sorted by string.
+    order: Vec<usize>,
+}
+
+impl SortedInterner {
+    pub fn new() -> Self {
+        Self {
+            store: CellStore::new(),
+            order: Vec::new(),
+        }
+    }
+
+    // Binary searches the index for the string. On a miss, the error
+    // holds where in the index the string belongs.
+    fn search(&self, val: &str) -> Result<usize, usize> {
+        self.order.binary_search_by(|id| self.value(*id).cmp(val))
+    }
+
+    // Returns the id of this string, if it is stored.
+    fn position(&self, val: &str) -> Option<usize> {
+        self.search(val).ok().map(|rank| self.order[rank])
+    }
+
+    // Returns where in the index the string stored at this position sits,
+    // if it is live.
+    fn value_rank(&self, position: usize) -> Option<usize> {
+        let value = self.store.get(position)?;
+        self.search(value).ok()
+    }
+
+    // Only live ids are held in the index, so they always have a value.
+    fn value(&self, id: usize) -> &str {
+        self.store.get(id).map_or("", String::as_str)
+    }
+
+    /// [has_type] reports whether the string stored under the id was
+    /// interned under the type, without searching for the string.
+    pub(crate) fn has_type(&self, id: SerialU64<()>, typ: &TypeId) -> bool {
+        self.store.has_type(id.get() as usize, typ)
+    }
+}
+
+impl Default for SortedInterner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Interner for SortedInterner {
+    fn intern(&mut self, val: String, typ: TypeId) -> Result<SerialU64<()>, InternErr> {
+        let rank = match self.search(&val) {
+            // • If the string is already stored, add the type to its cell.
+            Ok(rank) => {
+                let position = self.order[rank];
+                self.store.add_type(position, typ);
+                return Ok(to_id(position));
+            }
+            Err(rank) => rank,
+        };
+        // • Otherwise, store it under the next id, and slot that id
+        //   into the index where the string sorts.
+        let position = self.store.push(val, typ)?;
+        self.order.insert(rank, position);
+        Ok(to_id(position))
+    }
+
+    fn resolve(&self, id: SerialU64<()>) -> Option<String> {
+        self.store.get(id.get() as usize).cloned()
+    }
+
+    fn resolve_ref(&self, id: SerialU64<()>) -> Option<Cow<'_, str>> {
+        let value = self.store.get(id.get() as usize)?;
+        Some(Cow::Borrowed(value.as_str()))
+    }
+
+    fn get_interned(&self, val: String, typ: TypeId) -> Option<SerialU64<()>> {
+        self.get_interned_str(&val, typ)
+    }
+
+    fn get_interned_str(&self, val: &str, typ: TypeId) -> Option<SerialU64<()>> {
+        self.position(val)
+            .filter(|position| self.store.has_type(*position, &typ))
+            .map(to_id)
+    }
+
+    fn get_interned_any(&self, val: &str) -> Option<SerialU64<()>> {
+        self.position(val).map(to_id)
+    }
+
+    fn entries(&self) -> Vec<(SerialU64<()>, String, Vec<TypeId>)> {
+        self.store.entries(String::clone)
+    }
+
+    fn types_for(&self, val: &str) -> Vec<TypeId> {
+        self.position(val)
+            .map(|position| self.store.types(position))
+            .unwrap_or_default()
+    }
+
+    fn sorted_strings(&self) -> Vec<String> {
+        // The index is already in order.
+        self.order
+            .iter()
+            .map(|id| String::from(self.value(*id)))
+            .collect()
+    }
+
+    fn remove(&mut self, id: SerialU64<()>, typ: TypeId) -> bool {
+        let position = id.get() as usize;
+        let rank = self.value_rank(position);
+        if !self.store.remove(position, &typ) {
+            return false;
+        }
+        // • Once the string itself is gone, drop it from the index.
+        if self.store.get(position).is_none() {
+            if let Some(rank) = rank {
+                self.order.remove(rank);
+            }
+        }
+        true
+    }
+
+    fn clear(&mut self) {
+        self.store.clear();
+        self.order.clear();
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.store.reserve(additional);
+        self.order.reserve(additional);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.store.shrink_to_fit();
+        self.order.shrink_to_fit();
+    }
+
+    fn next_id(&self) -> Option<u64> {
+        Some(self.store.next_id())
+    }
+
+    fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    fn stats(&self) -> TableStats {
+        TableStats {
+            num_entries:           self.len(),
+            total_bytes_stored:    self.store.iter().map(|(_, value, _)| value.len()).sum(),
+            num_type_associations: self.store.num_type_associations(),
+            estimated_heap_bytes:  self.heap_size(),
+        }
+    }
+
+    fn heap_size(&self) -> usize {
+        let order = self.order.capacity() * size_of::<usize>();
+        self.store.heap_size(String::capacity) + order
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+
+    use super::SortedInterner;
+    use crate::{Interner, InternerFlavor, Symbol, SymbolTable};
+
+    #[test]
+    fn lookups_after_unsorted_inserts() {
+        let typ = TypeId::of::<String>();
+        let mut interner = SortedInterner::new();
+        let words = ["toad", "axolotl", "newt", "frog", "olm", "caecilian"];
+        let ids: Vec<_> = words
+            .iter()
+            .map(|word| interner.intern(word.to_string(), typ).unwrap())
+            .collect();
+        // • Ids follow insertion order, not sorted order.
+        for (position, id) in ids.iter().enumerate() {
+            assert_eq!(id.get(), position as u64 + 1);
+        }
+        for (word, id) in words.iter().zip(&ids) {
+            assert_eq!(interner.get_interned_str(word, typ), Some(*id));
+            assert_eq!(interner.resolve(*id).as_deref(), Some(*word));
+        }
+        assert_eq!(interner.get_interned_str("salamander", typ), None);
+        assert_eq!(
+            interner.sorted_strings(),
+            ["axolotl", "caecilian", "frog", "newt", "olm", "toad"]
+        );
+        assert!(interner.remove(ids[3], typ));
+        assert_eq!(interner.get_interned_str("frog", typ), None);
+        assert_eq!(interner.get_interned_str("newt", typ), Some(ids[2]));
+        assert_eq!(interner.len(), 5);
+    }
+
+    #[test]
+    fn sorted_flavor() {
+        let mut table = SymbolTable::new(InternerFlavor::Sorted);
+        let toad: Symbol<String> = table.intern(&"toad".to_owned());
+        let frog: Symbol<String> = table.intern(&"frog".to_owned());
+        assert_eq!(table.intern(&"toad".to_owned()), toad);
+        assert_eq!(table.resolve(&frog), Ok("frog".to_owned()));
+        let sorted: Vec<String> = table.iter_sorted().collect();
+        assert_eq!(sorted, ["frog", "toad"]);
+    }
+}
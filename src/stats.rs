@@ -0,0 +1,27 @@
+use alloc::collections::BTreeSet;
+use core::any::TypeId;
+use core::mem::size_of;
+
+/// [TableStats] summarizes how much a [SymbolTable] is storing, so the
+/// different [InternerFlavor]s can be compared empirically.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TableStats {
+    /// The number of distinct strings held by the table.
+    pub num_entries:           usize,
+    /// The number of bytes of string data the interner actually stores,
+    /// after any compression.
+    pub total_bytes_stored:    usize,
+    /// The number of (string, type) pairs held by the table. A string
+    /// interned under two types counts twice.
+    pub num_type_associations: usize,
+    /// An estimate of the bytes the interner holds on the heap, including
+    /// spare capacity. It is only an estimate: allocator overhead and the
+    /// internal nodes of tree-based collections aren't counted.
+    pub estimated_heap_bytes:  usize,
+}
+
+/// [type_set_bytes] estimates the heap bytes held by a set of [TypeId]s,
+/// counting only the ids themselves.
+pub(crate) fn type_set_bytes(typs: &BTreeSet<TypeId>) -> usize {
+    typs.len() * size_of::<TypeId>()
+}
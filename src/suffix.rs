@@ -0,0 +1,161 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::any::{Any, TypeId};
+
+use typed_ids::SerialU64;
+
+use super::{InternErr, Interner, RadixInterner, TableStats};
+
+/// A [SuffixInterner] stores [Symbol]s so strings which share a suffix
+/// (like domain names or qualified stack frames) only store that suffix once.
+/// It is the mirror image of a [RadixInterner]: each string is stored
+/// reversed in a radix trie, and resolving an id walks its node back up to
+/// the root, then reverses the result.
+#[derive(Clone, Debug, Default)]
+pub struct SuffixInterner {
+    // Holds every string reversed, so shared suffixes become shared prefixes.
+    reversed: RadixInterner,
+}
+
+impl SuffixInterner {
+    pub fn new() -> Self {
+        Self {
+            reversed: RadixInterner::new(),
+        }
+    }
+}
+
+// Reversing by chars, rather than by bytes, keeps the string valid UTF-8.
+// Reversing twice always restores the original string.
+pub(crate) fn reverse(val: &str) -> String {
+    val.chars().rev().collect()
+}
+
+impl Interner for SuffixInterner {
+    fn intern(&mut self, val: String, typ: TypeId) -> Result<SerialU64<()>, InternErr> {
+        self.reversed.intern(reverse(&val), typ)
+    }
+
+    fn resolve(&self, id: SerialU64<()>) -> Option<String> {
+        self.reversed.resolve(id).map(|val| reverse(&val))
+    }
+
+    fn get_interned(&self, val: String, typ: TypeId) -> Option<SerialU64<()>> {
+        self.get_interned_str(&val, typ)
+    }
+
+    fn get_interned_str(&self, val: &str, typ: TypeId) -> Option<SerialU64<()>> {
+        self.reversed.get_interned_str(&reverse(val), typ)
+    }
+
+    fn get_interned_any(&self, val: &str) -> Option<SerialU64<()>> {
+        self.reversed.get_interned_any(&reverse(val))
+    }
+
+    fn entries(&self) -> Vec<(SerialU64<()>, String, Vec<TypeId>)> {
+        self.reversed
+            .entries()
+            .into_iter()
+            .map(|(id, val, typs)| (id, reverse(&val), typs))
+            .collect()
+    }
+
+    fn types_for(&self, val: &str) -> Vec<TypeId> {
+        self.reversed.types_for(&reverse(val))
+    }
+
+    fn remove(&mut self, id: SerialU64<()>, typ: TypeId) -> bool {
+        self.reversed.remove(id, typ)
+    }
+
+    fn clear(&mut self) {
+        self.reversed.clear();
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.reversed.reserve(additional);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.reversed.shrink_to_fit();
+    }
+
+    fn next_id(&self) -> Option<u64> {
+        self.reversed.next_id()
+    }
+
+    fn len(&self) -> usize {
+        self.reversed.len()
+    }
+
+    fn stats(&self) -> TableStats {
+        self.reversed.stats()
+    }
+
+    fn heap_size(&self) -> usize {
+        self.reversed.heap_size()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+
+    use super::SuffixInterner;
+    use crate::array::ArrayInterner;
+    use crate::{Interner, InternerFlavor, Symbol, SymbolTable};
+
+    const NAMES: [&str; 6] = [
+        "com.example.a",
+        "com.example.b",
+        "mail.example.com",
+        "www.example.com",
+        "example.com",
+        "naïve.café",
+    ];
+
+    #[test]
+    fn round_trip() {
+        let typ = TypeId::of::<String>();
+        let mut interner = SuffixInterner::new();
+        let ids: Vec<_> = NAMES
+            .iter()
+            .map(|name| interner.intern(name.to_string(), typ).unwrap())
+            .collect();
+        for (name, id) in NAMES.iter().zip(&ids) {
+            assert_eq!(interner.resolve(*id).as_deref(), Some(*name));
+            assert_eq!(interner.get_interned(name.to_string(), typ), Some(*id));
+            assert_eq!(interner.intern(name.to_string(), typ).unwrap(), *id);
+        }
+        assert_eq!(interner.len(), NAMES.len());
+        assert_eq!(interner.get_interned(".com".to_string(), typ), None);
+        let strings: Vec<String> = interner.strings();
+        assert_eq!(strings, NAMES);
+    }
+
+    #[test]
+    fn stores_less_than_array() {
+        let typ = TypeId::of::<String>();
+        let mut suffix = SuffixInterner::new();
+        let mut array = ArrayInterner::new();
+        for name in NAMES {
+            suffix.intern(name.to_string(), typ).unwrap();
+            array.intern(name.to_string(), typ).unwrap();
+        }
+        assert!(suffix.stats().total_bytes_stored < array.stats().total_bytes_stored);
+    }
+
+    #[test]
+    fn suffix_flavor() {
+        let mut table = SymbolTable::new(InternerFlavor::Suffix);
+        let a: Symbol<String> = table.intern(&"com.example.a".to_owned());
+        let b: Symbol<String> = table.intern(&"com.example.b".to_owned());
+        assert_ne!(a, b);
+        assert_eq!(table.resolve(&a), Ok("com.example.a".to_owned()));
+        assert_eq!(table.resolve(&b), Ok("com.example.b".to_owned()));
+    }
+}
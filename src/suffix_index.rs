@@ -0,0 +1,189 @@
+use alloc::borrow::Cow;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::any::{Any, TypeId};
+use core::mem::size_of;
+
+use typed_ids::SerialU64;
+
+use super::{InternErr, Interner, SortedInterner, TableStats};
+use crate::cell_store::to_id;
+use crate::suffix::reverse;
+
+/// A [SuffixIndexInterner] is a [SortedInterner] which also keeps a
+/// secondary index of every string reversed. Strings which share a suffix
+/// share a prefix once reversed, so they sit next to each other in the
+/// index, and finding every string with a given suffix is a range query
+/// rather than a scan. Unlike the [SuffixInterner], which only stores the
+/// reversed strings to compress them, each string is stored twice.
+#[derive(Clone, Debug, Default)]
+pub struct SuffixIndexInterner {
+    // Stores the strings and issues their ids.
+    forward:  SortedInterner,
+    // Maps each live string, reversed, to its id.
+    reversed: BTreeMap<String, u64>,
+}
+
+impl SuffixIndexInterner {
+    pub fn new() -> Self {
+        Self {
+            forward:  SortedInterner::new(),
+            reversed: BTreeMap::new(),
+        }
+    }
+}
+
+impl Interner for SuffixIndexInterner {
+    fn intern(&mut self, val: String, typ: TypeId) -> Result<SerialU64<()>, InternErr> {
+        let backwards = reverse(&val);
+        let (id, is_new) = self.forward.intern_with_status(val, typ)?;
+        // • Only new strings need to be added to the index.
+        if is_new {
+            self.reversed.insert(backwards, id.get());
+        }
+        Ok(id)
+    }
+
+    fn resolve(&self, id: SerialU64<()>) -> Option<String> {
+        self.forward.resolve(id)
+    }
+
+    fn resolve_ref(&self, id: SerialU64<()>) -> Option<Cow<'_, str>> {
+        self.forward.resolve_ref(id)
+    }
+
+    fn get_interned(&self, val: String, typ: TypeId) -> Option<SerialU64<()>> {
+        self.forward.get_interned(val, typ)
+    }
+
+    fn get_interned_str(&self, val: &str, typ: TypeId) -> Option<SerialU64<()>> {
+        self.forward.get_interned_str(val, typ)
+    }
+
+    fn get_interned_any(&self, val: &str) -> Option<SerialU64<()>> {
+        self.forward.get_interned_any(val)
+    }
+
+    fn with_suffix(&self, suffix: &str, typ: TypeId) -> Vec<SerialU64<()>> {
+        // • Every string ending with the suffix starts with it once
+        //   reversed, so the matches form one run of the index.
+        let start = reverse(suffix);
+        // • The index holds each match's id, so only its types need
+        //   checking, rather than looking the string up again.
+        let mut ids: Vec<SerialU64<()>> = self
+            .reversed
+            .range(start.clone()..)
+            .take_while(|(backwards, _)| backwards.starts_with(&start))
+            .map(|(_, id)| to_id(*id as usize))
+            .filter(|id| self.forward.has_type(*id, &typ))
+            .collect();
+        // • Report the matches in id order, like the default.
+        ids.sort_unstable_by_key(|id| id.get());
+        ids
+    }
+
+    fn entries(&self) -> Vec<(SerialU64<()>, String, Vec<TypeId>)> {
+        self.forward.entries()
+    }
+
+    fn types_for(&self, val: &str) -> Vec<TypeId> {
+        self.forward.types_for(val)
+    }
+
+    fn sorted_strings(&self) -> Vec<String> {
+        self.forward.sorted_strings()
+    }
+
+    fn remove(&mut self, id: SerialU64<()>, typ: TypeId) -> bool {
+        let val = match self.forward.resolve(id) {
+            Some(val) => val,
+            None => return false,
+        };
+        if !self.forward.remove(id, typ) {
+            return false;
+        }
+        // • Once the string itself is gone, drop it from the index.
+        if !self.forward.contains_id(id) {
+            self.reversed.remove(&reverse(&val));
+        }
+        true
+    }
+
+    fn clear(&mut self) {
+        self.forward.clear();
+        self.reversed.clear();
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.forward.reserve(additional);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.forward.shrink_to_fit();
+    }
+
+    fn next_id(&self) -> Option<u64> {
+        self.forward.next_id()
+    }
+
+    fn len(&self) -> usize {
+        self.forward.len()
+    }
+
+    fn stats(&self) -> TableStats {
+        let forward = self.forward.stats();
+        let index_bytes: usize = self.reversed.keys().map(String::len).sum();
+        TableStats {
+            total_bytes_stored: forward.total_bytes_stored + index_bytes,
+            estimated_heap_bytes: self.heap_size(),
+            ..forward
+        }
+    }
+
+    fn heap_size(&self) -> usize {
+        let index: usize = self
+            .reversed
+            .keys()
+            .map(|backwards| backwards.capacity() + size_of::<(String, u64)>())
+            .sum();
+        self.forward.heap_size() + index
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+
+    use super::SuffixIndexInterner;
+    use crate::{Interner, InternerFlavor, Symbol, SymbolTable};
+
+    #[test]
+    fn index_follows_removals() {
+        let typ = TypeId::of::<String>();
+        let mut interner = SuffixIndexInterner::new();
+        let toad = interner.intern("toad".to_owned(), typ).unwrap();
+        let bread = interner.intern("bread".to_owned(), typ).unwrap();
+        interner.intern("frog".to_owned(), typ).unwrap();
+        assert_eq!(interner.with_suffix("ad", typ), [toad, bread]);
+        assert_eq!(interner.with_suffix("ad", TypeId::of::<str>()), []);
+        assert!(interner.remove(toad, typ));
+        assert_eq!(interner.with_suffix("ad", typ), [bread]);
+        interner.clear();
+        assert_eq!(interner.with_suffix("", typ), []);
+    }
+
+    #[test]
+    fn suffix_index_flavor() {
+        let mut table = SymbolTable::new(InternerFlavor::SuffixIndex);
+        let toad: Symbol<String> = table.intern(&"toad".to_owned());
+        let _: Symbol<String> = table.intern(&"frog".to_owned());
+        assert_eq!(table.resolve(&toad), Ok("toad".to_owned()));
+        assert_eq!(table.get_interned("toad"), Some(toad));
+        assert_eq!(table.len(), 2);
+    }
+}
@@ -1,12 +1,17 @@
-use std::cmp::Ordering;
-use std::fmt;
-use std::hash::{Hash, Hasher};
-use std::rc::Rc;
+use alloc::format;
+use alloc::rc::Rc;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Range;
 
 use typed_ids::SerialU64;
 
+use crate::errors::parse_resolved;
 use crate::internable::Internable;
-use crate::Interner;
+use crate::{CachedSymbol, Interner, ResolutionErr, SymbolStr, SymbolTable};
 
 /// A Symbol uniquely represents each String contained in
 /// the [SymbolTable]. It serves as a lookup key into the table,
@@ -16,23 +21,60 @@ use crate::Interner;
 #[derive(Clone)]
 pub struct Symbol<T: Internable + 'static> {
     // This ID maps the Symbol to an entry in the table.
-    id:     SerialU64<T>,
+    id:         SerialU64<T>,
     // This is a reference to the table storing the Symbol.
-    lookup: Rc<dyn Resolvable>,
+    lookup:     Rc<dyn Resolvable>,
+    // The table's generation when the Symbol was issued.
+    generation: u64,
 }
 
 impl<T: Internable + 'static> Symbol<T> {
     /// [new] will construct a new Symbol. This method is only
     /// intended for internal use.
     pub fn new<R: Resolvable + 'static>(id: SerialU64<T>, lookup: R) -> Self {
-        let lookup = Rc::new(lookup);
-        Self { id, lookup }
+        let generation = lookup.generation();
+        Self::at_generation(id, lookup, generation)
+    }
+
+    // Constructs a Symbol as though it was issued when its table was at the
+    // given generation, for copies of a table which outlive later clears.
+    pub(crate) fn at_generation<R: Resolvable + 'static>(
+        id: SerialU64<T>,
+        lookup: R,
+        generation: u64,
+    ) -> Self {
+        Self {
+            id,
+            lookup: Rc::new(lookup),
+            generation,
+        }
+    }
+
+    /// [null] returns the null Symbol of the table, which wraps the reserved
+    /// id 0 that no string is ever interned under. It serves as a "no symbol"
+    /// value where an [Option] would cost too much. The null Symbol never
+    /// resolves, and displays as the empty string, so `null().to_string()`
+    /// is `""`.
+    pub fn null(table: &SymbolTable) -> Self {
+        Self::new(SerialU64::try_from(0).unwrap(), table.clone())
+    }
+
+    /// [is_null] reports whether this is the [null] Symbol of its table.
+    pub fn is_null(&self) -> bool {
+        self.raw() == 0
     }
 
     pub fn id(&self) -> SerialU64<T> {
         self.id
     }
 
+    /// [raw] returns the Symbol's id as a plain integer, such as for passing
+    /// across an FFI boundary. Use [SymbolTable::symbol_from_raw] to
+    /// rebuild the Symbol.
+    pub fn raw(&self) -> u64 {
+        self.id.get()
+    }
+
     pub fn erase_type(&self) -> SerialU64<()> {
         let id_unwrapped = self.id.get();
         SerialU64::<()>::try_from(id_unwrapped).unwrap()
@@ -42,25 +84,196 @@ impl<T: Internable + 'static> Symbol<T> {
         self.lookup.addr()
     }
 
-    /// # Panics
-    /// This method panics if the recovered string cannot be
+    /// [starts_with] reports whether the interned string begins with `prefix`.
+    /// A Symbol which can't be resolved starts with nothing.
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.raw_value().is_some_and(|val| val.starts_with(prefix))
+    }
+
+    /// [ends_with] reports whether the interned string ends with `suffix`.
+    /// A Symbol which can't be resolved ends with nothing.
+    pub fn ends_with(&self, suffix: &str) -> bool {
+        self.raw_value().is_some_and(|val| val.ends_with(suffix))
+    }
+
+    /// [as_str] returns a view of the interned string which derefs to `&str`,
+    /// without parsing it back into a `T`. A Symbol which can't be resolved,
+    /// such as a stale one, is viewed as the empty string `""`, the same as
+    /// an interned empty string; use [resolve] to tell the two apart.
+    pub fn as_str(&self) -> SymbolStr<'_> {
+        SymbolStr::new(self.raw_value().unwrap_or_default())
+    }
+
+    /// [cache] resolves the Symbol once and keeps its string, for hot loops
+    /// which format the same Symbol many times. The cached string matches
+    /// the Symbol's [Display] output, including its placeholder when the
+    /// Symbol can't be resolved.
+    pub fn cache(&self) -> CachedSymbol<T> {
+        CachedSymbol::new(self.id, self.to_string())
+    }
+
+    /// [char_len] returns the number of [char]s in the interned string.
+    /// A Symbol which can't be resolved has a length of zero.
+    pub fn char_len(&self) -> usize {
+        self.raw_value().map_or(0, |val| val.chars().count())
+    }
+
+    /// [byte_len] returns the number of UTF-8 bytes in the interned string.
+    /// A Symbol which can't be resolved has a length of zero.
+    pub fn byte_len(&self) -> usize {
+        self.raw_value().map_or(0, |val| val.len())
+    }
+
+    /// [char_at] returns the char at `index` in the interned string, counting
+    /// chars rather than bytes. It returns [None] if the index is past the
+    /// end, or the Symbol can't be resolved.
+    pub fn char_at(&self, index: usize) -> Option<char> {
+        self.raw_value()?.chars().nth(index)
+    }
+
+    /// [slice] extracts the chars in `range` from the interned string. The
+    /// range counts chars, not bytes, so it never splits a char. A range
+    /// reaching past the end is clamped to the end, and a range which is
+    /// empty or starts past the end yields the empty string.
+    pub fn slice(&self, range: Range<usize>) -> String {
+        let len = range.end.saturating_sub(range.start);
+        self.as_str().chars().skip(range.start).take(len).collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.byte_len() == 0
+    }
+
+    /// [content_hash] hashes the interned string, rather than the id, so
+    /// Symbols for equal strings share a hash even across tables. This is
+    /// for building content-addressed maps; the [Hash] impl remains id-based
+    /// for use within a single table. The hash is 64-bit FNV-1a, so it is
+    /// stable across runs and platforms. A Symbol which can't be resolved
+    /// hashes like the empty string.
+    pub fn content_hash(&self) -> u64 {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0100_0000_01b3;
+        self.as_str().bytes().fold(FNV_OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+        })
+    }
+
+    /// [lexical_cmp] orders two Symbols alphabetically by their interned
+    /// strings. Unlike the [Ord] impl, which compares ids in O(1) and so
+    /// orders Symbols by when they were first interned, this resolves both
+    /// Symbols and compares the strings byte by byte. A Symbol which can't
+    /// be resolved sorts before every Symbol which can.
+    pub fn lexical_cmp(&self, other: &Self) -> Ordering {
+        self.raw_value().cmp(&other.raw_value())
+    }
+
+    /// [common_prefix_len] returns the number of leading chars the interned
+    /// strings of the two Symbols share. Identical Symbols share their whole
+    /// string, which is counted without resolving it twice. A Symbol which
+    /// can't be resolved shares nothing.
+    pub fn common_prefix_len(&self, other: &Self) -> usize {
+        if self == other {
+            return self.char_len();
+        }
+        match (self.raw_value(), other.raw_value()) {
+            (Some(a), Some(b)) => a.chars().zip(b.chars()).take_while(|(x, y)| x == y).count(),
+            _ => 0,
+        }
+    }
+
+    /// [same_string] reports whether the two Symbols hold the same string,
+    /// even if they came from different tables. Unlike `==`, which compares
+    /// ids and origins in O(1), this resolves both Symbols and compares the
+    /// strings. A Symbol which can't be resolved matches nothing.
+    pub fn same_string(&self, other: &Self) -> bool {
+        match (self.raw_value(), other.raw_value()) {
+            (Some(a), Some(b)) => a == b,
+            _ => false,
+        }
+    }
+
+    /// [edit_distance] returns the Levenshtein distance between the two
+    /// Symbols' strings: the fewest single-char insertions, deletions, and
+    /// substitutions turning one into the other. Equal Symbols are 0 apart
+    /// without being resolved. A Symbol which can't be resolved is treated
+    /// as the empty string. It takes O(n·m) time, for strings of n and m
+    /// chars.
+    pub fn edit_distance(&self, other: &Self) -> usize {
+        if self == other {
+            return 0;
+        }
+        let source: Vec<char> = self.as_str().chars().collect();
+        let target = other.as_str();
+        // • Keep only the previous row of the table, where row[j] is the
+        //   distance from the source prefix to the first j target chars.
+        let mut row: Vec<usize> = (0..=source.len()).collect();
+        for (i, target_char) in target.chars().enumerate() {
+            let mut diagonal = row[0];
+            row[0] = i + 1;
+            for (j, source_char) in source.iter().enumerate() {
+                let substitution = diagonal + usize::from(*source_char != target_char);
+                diagonal = row[j + 1];
+                row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+            }
+        }
+        row[source.len()]
+    }
+
+    /// [to_token] writes the Symbol as a self-describing `"<id>:<string>"`
+    /// token, for logging. The id is only a hint; [SymbolTable::from_token]
+    /// reads a token back by its string. It returns [None] if the Symbol
+    /// can't be resolved, rather than writing a token which would read back
+    /// as the empty string.
+    pub fn to_token(&self) -> Option<String> {
+        Some(format!("{}:{}", self.raw(), self.raw_value()?))
+    }
+
+    /// [is_stale] reports whether the Symbol's table has been cleared or
+    /// drained since the Symbol was issued. A stale Symbol never resolves.
+    pub fn is_stale(&self) -> bool {
+        self.lookup.generation() != self.generation
+    }
+
+    pub(crate) fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    // Fetches the interned string without parsing it back into a T.
+    pub(crate) fn raw_value(&self) -> Option<String> {
+        if self.is_stale() {
+            return None;
+        }
+        self.lookup.resolve(self.erase_type())
+    }
+
+    /// [resolve] recovers the interned value from the table holding this
+    /// [Symbol], without the caller needing a reference to the table. It
+    /// mirrors [SymbolTable::resolve], failing if the Symbol is stale, if the
+    /// table no longer knows this id, or if the recovered string cannot be
     /// parsed back into the type that generated it.
-    fn into(&self) -> T {
-        let erased = self.erase_type();
-        let interned_string = self.lookup.resolve(erased);
-        let value = T::try_from(interned_string);
-        match value {
-            Ok(item) => item,
-            Err(_) => panic!("Interned value was not recoverable."),
+    pub fn resolve(&self) -> Result<T, ResolutionErr<T>> {
+        if self.is_stale() {
+            return Err(ResolutionErr::StaleSymbolErr);
         }
+        let erased = self.erase_type();
+        let interned_string = self
+            .lookup
+            .resolve(erased)
+            .ok_or(ResolutionErr::UnknownIdErr)?;
+        parse_resolved(&interned_string)
     }
 }
 
+/// Displaying a Symbol never fails: if it can't be resolved, or its string
+/// can't be parsed back into a `T`, a placeholder naming its id is written.
 impl<T: Internable + 'static> fmt::Display for Symbol<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let recovered_item: T = self.into();
-        let as_string: String = recovered_item.as_ref().to_string();
-        write!(f, "{}", as_string)
+        match self.resolve() {
+            Ok(recovered_item) => write!(f, "{}", recovered_item.as_ref()),
+            // The null Symbol stands for no string at all.
+            Err(_) if self.is_null() => Ok(()),
+            Err(_) => write!(f, "<unresolvable symbol #{}>", self.raw()),
+        }
     }
 }
 
@@ -72,18 +285,46 @@ impl<T: Internable + 'static> fmt::Debug for Symbol<T> {
 
 impl<T: Internable + 'static> PartialEq for Symbol<T> {
     fn eq(&self, other: &Self) -> bool {
-        self.id == other.id && self.lookup.addr() == other.lookup.addr()
+        self.id == other.id
+            && self.lookup.addr() == other.lookup.addr()
+            && self.generation == other.generation
     }
 }
 
 impl<T: Internable + 'static> Eq for Symbol<T> {}
 
+// Comparing a Symbol against a string has to resolve the Symbol first, so
+// unlike Symbol-to-Symbol comparison, these are not O(1) and they allocate.
+// A Symbol which can't be resolved is equal to no string. There is no
+// matching `Borrow<str>`, since the Symbol doesn't own its string and
+// hashes by id rather than by content.
+impl<T: Internable + 'static> PartialEq<str> for Symbol<T> {
+    fn eq(&self, other: &str) -> bool {
+        self.raw_value().is_some_and(|val| val == other)
+    }
+}
+
+impl<T: Internable + 'static> PartialEq<&str> for Symbol<T> {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl<T: Internable + 'static> PartialEq<String> for Symbol<T> {
+    fn eq(&self, other: &String) -> bool {
+        self == other.as_str()
+    }
+}
+
 impl<T: Internable + 'static> Hash for Symbol<T> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.id.hash(state);
     }
 }
 
+/// Symbols are ordered by id, which is fast but reflects the order strings
+/// were first interned in, rather than their contents. Use
+/// [Symbol::lexical_cmp] for alphabetical order.
 impl<T: Internable + 'static> Ord for Symbol<T> {
     fn cmp(&self, other: &Self) -> Ordering {
         self.id.cmp(&other.id)
@@ -96,6 +337,12 @@ impl<T: Internable + 'static> PartialOrd for Symbol<T> {
     }
 }
 
+impl<T: Internable + 'static> From<Symbol<T>> for SerialU64<T> {
+    fn from(sym: Symbol<T>) -> Self {
+        sym.id()
+    }
+}
+
 // This attempt to practice the "Accept Interface, Return Structs"
 // best-practice. Symbols need to hold reference to a [SymbolTable], but
 // [Symbol]s might outlive their table, so they need to hold a reference-counted
@@ -103,22 +350,268 @@ impl<T: Internable + 'static> PartialOrd for Symbol<T> {
 /// A type is resolvable if it implements the resolution API for
 /// [Interner]s.
 pub trait Resolvable {
-    fn resolve(&self, id: SerialU64<()>) -> String;
+    fn resolve(&self, id: SerialU64<()>) -> Option<String>;
 
     /// This function returns the address of the backing table.
     /// This allows [Symbol]s to ensure they are being compared
     /// against the table from which they originated.
     fn addr(&self) -> *const (dyn Interner + 'static);
+
+    /// [generation] counts how many times the table has been cleared or
+    /// drained. A [Symbol] issued under an earlier generation is stale.
+    /// Tables which are never cleared can rely on the default of zero.
+    fn generation(&self) -> u64 {
+        0
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::cmp::Ordering;
+
     use static_assertions::assert_obj_safe;
+    use typed_ids::SerialU64;
 
-    use super::Resolvable;
+    use super::{Resolvable, Symbol};
+    use crate::table::tests::Lowercase;
+    use crate::{InternerFlavor, SymbolTable};
+
+    fn intern(table: &mut SymbolTable, val: &str) -> Symbol<String> {
+        table.intern(&val.to_owned())
+    }
 
     #[test]
     fn resolvable_is_obj_safe() {
         assert_obj_safe!(Resolvable);
     }
+
+    #[test]
+    fn prefix_and_suffix() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let toad = intern(&mut table, "toad");
+        assert!(toad.starts_with("to"));
+        assert!(toad.starts_with(""));
+        assert!(!toad.starts_with("ad"));
+        assert!(toad.ends_with("ad"));
+        assert!(!toad.ends_with("toads"));
+    }
+
+    #[test]
+    fn prefix_and_suffix_multibyte() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let cafe = intern(&mut table, "café");
+        assert!(cafe.starts_with("caf"));
+        assert!(cafe.ends_with("é"));
+        assert!(cafe.ends_with("fé"));
+        assert!(!cafe.ends_with("e"));
+        let kanji = intern(&mut table, "日本語");
+        assert!(kanji.starts_with("日本"));
+        assert!(kanji.ends_with("語"));
+    }
+
+    #[test]
+    fn lengths() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let toad = intern(&mut table, "toad");
+        assert_eq!(toad.char_len(), 4);
+        assert_eq!(toad.byte_len(), 4);
+        assert!(!toad.is_empty());
+        let cafe = intern(&mut table, "café");
+        assert_eq!(cafe.char_len(), 4);
+        assert_eq!(cafe.byte_len(), 5);
+        let empty = intern(&mut table, "");
+        assert_eq!(empty.char_len(), 0);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn resolves_without_table() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let toad = intern(&mut table, "toad");
+        assert_eq!(toad.resolve(), Ok("toad".to_owned()));
+        table.clear();
+        assert!(toad.resolve().is_err());
+    }
+
+    #[test]
+    fn as_str_derefs() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let toad = intern(&mut table, "toad");
+        let frog = intern(&mut table, "frog");
+        assert_eq!(toad.as_str(), "toad");
+        assert_eq!(frog.as_str(), "frog");
+        assert_eq!(toad.as_str().len(), 4);
+        assert!(frog.as_str().starts_with('f'));
+        // • Once the table is cleared, the view falls back to "".
+        table.clear();
+        assert_eq!(toad.as_str(), "");
+    }
+
+    #[test]
+    fn eq_strings() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let toad = intern(&mut table, "toad");
+        assert_eq!(toad, "toad");
+        assert_eq!(toad, "toad".to_owned());
+        assert!(toad == *"toad");
+        assert_ne!(toad, "frog");
+        table.clear();
+        assert_ne!(toad, "toad");
+    }
+
+    #[test]
+    fn lexical_order() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let toad = intern(&mut table, "toad");
+        let frog = intern(&mut table, "frog");
+        assert!(toad < frog);
+        assert_eq!(toad.lexical_cmp(&frog), Ordering::Greater);
+        assert_eq!(frog.lexical_cmp(&toad), Ordering::Less);
+        assert_eq!(toad.lexical_cmp(&toad), Ordering::Equal);
+    }
+
+    #[test]
+    fn raw_round_trip() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let toad = intern(&mut table, "toad");
+        let raw = toad.raw();
+        assert_eq!(raw, toad.id().get());
+        assert_eq!(table.symbol_from_raw::<String>(raw), Some(toad));
+        assert_eq!(table.symbol_from_raw::<String>(0), None);
+        assert_eq!(table.symbol_from_raw::<String>(raw + 1), None);
+        assert_eq!(table.symbol_from_raw::<String>(u64::MAX), None);
+        assert!(table.symbol_from_raw::<Box<str>>(raw).is_none());
+    }
+
+    #[test]
+    fn into_id() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let toad = intern(&mut table, "toad");
+        let expected = toad.id();
+        let id: SerialU64<String> = toad.into();
+        assert_eq!(id, expected);
+    }
+
+    #[test]
+    fn display_falls_back() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let toad = table.intern(&Lowercase("toad".to_owned()));
+        let shout = table.intern(&Lowercase("TOAD".to_owned()));
+        assert_eq!(toad.to_string(), "toad");
+        assert_eq!(
+            shout.to_string(),
+            format!("<unresolvable symbol #{}>", shout.raw())
+        );
+        let frog = intern(&mut table, "frog");
+        table.clear();
+        assert_eq!(
+            format!("{:?}", frog),
+            format!("<unresolvable symbol #{}>", frog.raw())
+        );
+    }
+
+    #[test]
+    fn content_hash_spans_tables() {
+        let mut left = SymbolTable::new(InternerFlavor::Array);
+        let mut right = SymbolTable::new(InternerFlavor::Radix);
+        intern(&mut right, "frog");
+        let toad = intern(&mut left, "toad");
+        let other_toad = intern(&mut right, "toad");
+        let frog = intern(&mut left, "frog");
+        assert_ne!(toad, other_toad);
+        assert_eq!(toad.content_hash(), other_toad.content_hash());
+        assert_ne!(toad.content_hash(), frog.content_hash());
+        // The FNV-1a hash of the empty string is its offset basis.
+        let empty = intern(&mut left, "");
+        assert_eq!(empty.content_hash(), 0xcbf2_9ce4_8422_2325);
+    }
+
+    #[test]
+    fn slice_clamps() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let toad = intern(&mut table, "toad");
+        assert_eq!(toad.slice(0..2), "to");
+        assert_eq!(toad.slice(1..3), "oa");
+        assert_eq!(toad.slice(2..10), "ad");
+        assert_eq!(toad.slice(4..5), "");
+        #[allow(clippy::reversed_empty_ranges)]
+        let backwards = 3..1;
+        assert_eq!(toad.slice(backwards), "");
+        let cafe = intern(&mut table, "café");
+        assert_eq!(cafe.slice(3..4), "é");
+    }
+
+    #[test]
+    fn common_prefix_len() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let toad = intern(&mut table, "toad");
+        let toast = intern(&mut table, "toast");
+        let frog = intern(&mut table, "frog");
+        let cafe = intern(&mut table, "café");
+        let cafes = intern(&mut table, "cafés");
+        assert_eq!(toad.common_prefix_len(&toast), 3);
+        assert_eq!(toast.common_prefix_len(&toad), 3);
+        assert_eq!(toad.common_prefix_len(&frog), 0);
+        assert_eq!(toad.common_prefix_len(&toad), 4);
+        assert_eq!(cafe.common_prefix_len(&cafes), 4);
+        assert_eq!(cafes.common_prefix_len(&cafes), 5);
+    }
+
+    #[test]
+    fn char_at() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let toad = intern(&mut table, "toad");
+        let cafe = intern(&mut table, "café");
+        assert_eq!(toad.char_at(0), Some('t'));
+        assert_eq!(toad.char_at(2), Some('a'));
+        assert_eq!(toad.char_at(4), None);
+        assert_eq!(toad.char_at(9), None);
+        assert_eq!(cafe.char_at(3), Some('é'));
+    }
+
+    #[test]
+    fn same_string_across_tables() {
+        let mut left = SymbolTable::new(InternerFlavor::Array);
+        let mut right = SymbolTable::new(InternerFlavor::Radix);
+        let _ = intern(&mut right, "frog");
+        let toad = intern(&mut left, "toad");
+        let other_toad = intern(&mut right, "toad");
+        assert_ne!(toad, other_toad);
+        assert!(toad.same_string(&other_toad));
+        assert!(toad.same_string(&toad));
+        let frog = intern(&mut left, "frog");
+        assert!(!toad.same_string(&frog));
+        right.clear();
+        assert!(!toad.same_string(&other_toad));
+    }
+
+    #[test]
+    fn null_symbol() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let null = Symbol::<String>::null(&table);
+        let toad = intern(&mut table, "toad");
+        assert!(null.is_null());
+        assert!(!toad.is_null());
+        assert_eq!(null.to_string(), "");
+        assert!(null.resolve().is_err());
+        assert_eq!(null, Symbol::null(&table));
+        assert_ne!(null, toad);
+    }
+
+    #[test]
+    fn edit_distance() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let toad = intern(&mut table, "toad");
+        let toast = intern(&mut table, "toast");
+        let empty = intern(&mut table, "");
+        assert_eq!(toad.edit_distance(&toast), 2);
+        assert_eq!(toast.edit_distance(&toad), 2);
+        assert_eq!(toad.edit_distance(&toad.clone()), 0);
+        assert_eq!(toad.edit_distance(&empty), 4);
+        let cafe = intern(&mut table, "café");
+        let cafes = intern(&mut table, "cafés");
+        assert_eq!(cafe.edit_distance(&cafes), 1);
+        let mut other = SymbolTable::new(InternerFlavor::Radix);
+        assert_eq!(toad.edit_distance(&intern(&mut other, "toad")), 0);
+    }
 }
@@ -0,0 +1,73 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+
+use crate::internable::Internable;
+
+/// A [Symbol32] is the compact counterpart of a [Symbol], issued by a
+/// [SymbolTable32]. It holds nothing but a 32-bit id, so it is four bytes
+/// wide and [Copy]. Unlike a [Symbol], it doesn't hold a reference to its
+/// table, so it must be resolved through [SymbolTable32::resolve], and
+/// resolving it against a table other than the one which issued it can't be
+/// detected. Comparisons between [Symbol32]s are O(1).
+pub struct Symbol32<T: Internable + 'static> {
+    id:  u32,
+    // Ties the id to its type without owning a `T`, so the Symbol32 is
+    // Copy, Send, and Sync no matter what `T` is.
+    typ: PhantomData<fn() -> T>,
+}
+
+impl<T: Internable + 'static> Symbol32<T> {
+    pub(crate) fn new(id: u32) -> Self {
+        Self {
+            id,
+            typ: PhantomData,
+        }
+    }
+
+    /// [raw] returns the Symbol32's id as a plain integer.
+    pub fn raw(&self) -> u32 {
+        self.id
+    }
+}
+
+impl<T: Internable + 'static> Clone for Symbol32<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Internable + 'static> Copy for Symbol32<T> {}
+
+impl<T: Internable + 'static> fmt::Debug for Symbol32<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Symbol32({})", self.id)
+    }
+}
+
+impl<T: Internable + 'static> PartialEq for Symbol32<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl<T: Internable + 'static> Eq for Symbol32<T> {}
+
+impl<T: Internable + 'static> Hash for Symbol32<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl<T: Internable + 'static> Ord for Symbol32<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
+impl<T: Internable + 'static> PartialOrd for Symbol32<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,142 @@
+use alloc::collections::VecDeque;
+use alloc::string::{String, ToString};
+use core::fmt;
+
+use super::internable::Internable;
+use super::Symbol;
+
+/// A [SymbolByteIterator] walks the UTF-8 bytes of a [Symbol]'s string,
+/// for lexers which work on raw bytes rather than [char]s. Otherwise, it
+/// behaves just like a [SymbolIterator].
+#[derive(Clone)]
+pub struct SymbolByteIterator<T: Internable + 'static> {
+    source:    Symbol<T>,
+    // The Symbol's string, captured once, so the iterator is unaffected
+    // if the Symbol later becomes stale.
+    original:  String,
+    remaining: VecDeque<u8>,
+}
+
+impl<T: Internable + 'static> PartialEq for SymbolByteIterator<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source && self.remaining == other.remaining
+    }
+}
+
+impl<T: Internable + 'static> SymbolByteIterator<T> {
+    pub fn new(source: Symbol<T>) -> Self {
+        let original = source.to_string();
+        let remaining: VecDeque<u8> = original.bytes().collect();
+        Self {
+            source,
+            original,
+            remaining,
+        }
+    }
+
+    pub fn has_next(&self) -> bool {
+        !self.remaining.is_empty()
+    }
+
+    pub fn peek(&self) -> Option<u8> {
+        self.remaining.front().cloned()
+    }
+}
+
+impl<T: Internable + 'static> Iterator for SymbolByteIterator<T> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.remaining.pop_front()
+    }
+}
+
+impl<T: Internable + 'static> DoubleEndedIterator for SymbolByteIterator<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.remaining.pop_back()
+    }
+}
+
+impl<T: Internable + 'static> fmt::Display for SymbolByteIterator<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl<T: Internable + 'static> fmt::Debug for SymbolByteIterator<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let num_matched = self.original.len() - self.remaining.len();
+        // Write the leading quote mark.
+        write!(f, "\"")?;
+        for (i, byte) in self.original.bytes().enumerate() {
+            // Write the index pointer.
+            if i == num_matched {
+                write!(f, "•")?;
+            }
+            // Write the next byte, escaping anything that isn't
+            // printable ASCII, since it may be part of a wider char.
+            write!(f, "{}", byte.escape_ascii())?;
+        }
+        // Special case: if the iterator is empty,
+        // then we have to write the dot at the end.
+        if !self.has_next() {
+            write!(f, "•")?;
+        }
+        // Write the closing quote mark.
+        write!(f, "\"")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use super::super::{InternerFlavor, Symbol, SymbolIterator, SymbolTable};
+    use super::SymbolByteIterator;
+
+    fn cafe() -> Symbol<String> {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        table.intern(&"café".to_owned())
+    }
+
+    #[test]
+    fn bytes_vs_chars() {
+        let sym = cafe();
+        assert_eq!(SymbolByteIterator::new(sym.clone()).count(), 5);
+        assert_eq!(SymbolIterator::new(sym).count(), 4);
+    }
+
+    #[test]
+    fn iterable() {
+        let mut bytes = SymbolByteIterator::new(cafe());
+        assert_eq!(bytes.peek(), Some(b'c'));
+        assert_eq!(bytes.next(), Some(b'c'));
+        assert_eq!(bytes.next_back(), Some(0xa9));
+        assert_eq!(bytes.next_back(), Some(0xc3));
+        assert_eq!(bytes.next(), Some(b'a'));
+        assert_eq!(bytes.next(), Some(b'f'));
+        assert!(!bytes.has_next());
+        assert_eq!(bytes.peek(), None);
+        assert_eq!(bytes.next(), None);
+    }
+
+    #[test]
+    fn display() {
+        let mut bytes = SymbolByteIterator::new(cafe());
+        assert_str_eq!(bytes.to_string(), "\"•caf\\xc3\\xa9\"");
+        bytes.nth(3);
+        assert_str_eq!(bytes.to_string(), "\"caf\\xc3•\\xa9\"");
+        bytes.next();
+        assert_str_eq!(bytes.to_string(), "\"caf\\xc3\\xa9•\"");
+    }
+
+    #[test]
+    fn display_outlives_a_stale_symbol() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let sym: Symbol<String> = table.intern(&"toad".to_owned());
+        let mut bytes = SymbolByteIterator::new(sym);
+        bytes.next();
+        table.clear();
+        assert_str_eq!(bytes.to_string(), "\"t•oad\"");
+    }
+}
@@ -1,5 +1,6 @@
-use std::collections::VecDeque;
-use std::fmt;
+use alloc::collections::VecDeque;
+use alloc::string::{String, ToString};
+use core::fmt;
 
 use super::internable::Internable;
 use super::Symbol;
@@ -7,6 +8,10 @@ use super::Symbol;
 #[derive(Clone)]
 pub struct SymbolIterator<T: Internable + 'static> {
     source:    Symbol<T>,
+    // The Symbol's string, captured once, so the iterator is unaffected
+    // if the Symbol later becomes stale.
+    original:  String,
+    num_chars: usize,
     remaining: VecDeque<char>,
 }
 
@@ -18,8 +23,14 @@ impl<T: Internable + 'static> PartialEq for SymbolIterator<T> {
 
 impl<T: Internable + 'static> SymbolIterator<T> {
     pub fn new(source: Symbol<T>) -> Self {
-        let remaining: VecDeque<char> = source.to_string().chars().collect();
-        Self { source, remaining }
+        let original = source.to_string();
+        let remaining: VecDeque<char> = original.chars().collect();
+        Self {
+            source,
+            original,
+            num_chars: remaining.len(),
+            remaining,
+        }
     }
 
     pub fn has_next(&self) -> bool {
@@ -29,6 +40,55 @@ impl<T: Internable + 'static> SymbolIterator<T> {
     pub fn peek(&self) -> Option<char> {
         self.remaining.front().cloned()
     }
+
+    /// [peek_n] returns the char `n` positions ahead without consuming
+    /// anything, so `peek_n(0)` is the same as [peek].
+    pub fn peek_n(&self, n: usize) -> Option<char> {
+        self.remaining.get(n).cloned()
+    }
+
+    /// [peek_str] collects up to the next `n` chars without consuming them.
+    /// Near the end of the symbol, fewer than `n` chars are returned.
+    pub fn peek_str(&self, n: usize) -> String {
+        self.remaining.iter().take(n).collect()
+    }
+
+    /// [reset] rewinds the iterator back to the start of the symbol.
+    pub fn reset(&mut self) {
+        self.remaining = self.original.chars().collect();
+    }
+
+    /// [position] returns how many chars have been consumed from the front.
+    pub fn position(&self) -> usize {
+        self.num_chars - self.remaining.len()
+    }
+
+    /// [remaining_str] collects the chars which haven't been consumed yet.
+    pub fn remaining_str(&self) -> String {
+        self.remaining.iter().collect()
+    }
+
+    /// [consumed_str] collects the chars which have already been consumed,
+    /// which is everything before the `•` marker in the Debug output.
+    pub fn consumed_str(&self) -> String {
+        self.original.chars().take(self.position()).collect()
+    }
+
+    /// [split_at_cursor] splits the chars at the current position, returning
+    /// the [consumed_str] and [remaining_str] together.
+    pub fn split_at_cursor(&self) -> (String, String) {
+        (self.consumed_str(), self.remaining_str())
+    }
+
+    /// [seek] moves the iterator so exactly `index` chars have been
+    /// consumed from the front, rewinding or skipping ahead as needed.
+    /// Seeking past the end leaves the iterator empty. Like [reset], this
+    /// also restores any chars consumed from the back.
+    pub fn seek(&mut self, index: usize) {
+        self.reset();
+        let skipped = index.min(self.remaining.len());
+        self.remaining.drain(..skipped);
+    }
 }
 
 impl<T: Internable + 'static> Iterator for SymbolIterator<T> {
@@ -37,6 +97,28 @@ impl<T: Internable + 'static> Iterator for SymbolIterator<T> {
     fn next(&mut self) -> Option<Self::Item> {
         self.remaining.pop_front()
     }
+
+    // Skips the first n chars in one shot, rather than one at a time.
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let skipped = n.min(self.remaining.len());
+        self.remaining.drain(..skipped);
+        self.remaining.pop_front()
+    }
+
+    fn count(self) -> usize {
+        self.remaining.len()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining.len();
+        (len, Some(len))
+    }
+}
+
+impl<T: Internable + 'static> ExactSizeIterator for SymbolIterator<T> {
+    fn len(&self) -> usize {
+        self.remaining.len()
+    }
 }
 
 impl<T: Internable + 'static> DoubleEndedIterator for SymbolIterator<T> {
@@ -45,6 +127,12 @@ impl<T: Internable + 'static> DoubleEndedIterator for SymbolIterator<T> {
     }
 }
 
+impl<T: Internable + 'static> From<Symbol<T>> for SymbolIterator<T> {
+    fn from(source: Symbol<T>) -> Self {
+        Self::new(source)
+    }
+}
+
 impl<T: Internable + 'static> fmt::Display for SymbolIterator<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:?}", self)
@@ -53,11 +141,10 @@ impl<T: Internable + 'static> fmt::Display for SymbolIterator<T> {
 
 impl<T: Internable + 'static> fmt::Debug for SymbolIterator<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let original = self.source.to_string();
-        let num_matched = original.len() - self.remaining.len();
+        let num_matched = self.position();
         // Write the leading quote mark.
         write!(f, "\"")?;
-        for (i, char) in original.chars().enumerate() {
+        for (i, char) in self.original.chars().enumerate() {
             // Write the index pointer.
             if i == num_matched {
                 write!(f, "•")?;
@@ -138,4 +225,133 @@ mod tests {
         assert_eq!(toad.peek(), None);
         assert_eq!(toad.next(), None);
     }
+
+    #[test]
+    fn nth_matches_next() {
+        let mut skipped = toad_iter();
+        let mut stepped = skipped.clone();
+        assert_eq!(skipped.nth(2), Some('a'));
+        stepped.next();
+        stepped.next();
+        stepped.next();
+        assert_eq!(skipped, stepped);
+        assert_str_eq!(skipped.to_string(), "\"toa•d\"");
+        assert_eq!(skipped.nth(5), None);
+        assert!(!skipped.has_next());
+    }
+
+    #[test]
+    fn exact_size() {
+        let mut toad = toad_iter();
+        assert_eq!(toad.len(), 4);
+        toad.next();
+        assert_eq!(toad.len(), 3);
+        assert_eq!(toad.clone().count(), 3);
+        toad.next_back();
+        assert_eq!(toad.len(), 2);
+    }
+
+    #[test]
+    fn reset_rewinds() {
+        let mut toad = toad_iter();
+        let fresh = toad.clone();
+        toad.next();
+        toad.next();
+        assert_eq!(toad.position(), 2);
+        toad.reset();
+        assert_eq!(toad.position(), 0);
+        assert_eq!(toad, fresh);
+        assert_str_eq!(toad.to_string(), "\"•toad\"");
+    }
+
+    #[test]
+    fn seek_moves_marker() {
+        let mut toad = toad_iter();
+        toad.seek(3);
+        assert_eq!(toad.position(), 3);
+        assert_str_eq!(toad.to_string(), "\"toa•d\"");
+        toad.seek(1);
+        assert_eq!(toad.peek(), Some('o'));
+        assert_str_eq!(toad.to_string(), "\"t•oad\"");
+        toad.seek(10);
+        assert!(!toad.has_next());
+        assert_str_eq!(toad.to_string(), "\"toad•\"");
+    }
+
+    #[test]
+    fn position_counts_chars() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let sym: Symbol<String> = table.intern(&"café!".to_owned());
+        let mut cafe = SymbolIterator::new(sym);
+        cafe.seek(4);
+        assert_eq!(cafe.position(), 4);
+        assert_eq!(cafe.peek(), Some('!'));
+        assert_str_eq!(cafe.to_string(), "\"café•!\"");
+    }
+
+    #[test]
+    fn consumed_and_remaining() {
+        let mut toad = toad_iter();
+        assert_str_eq!(toad.consumed_str(), "");
+        assert_str_eq!(toad.remaining_str(), "toad");
+        toad.next();
+        assert_str_eq!(toad.consumed_str(), "t");
+        assert_str_eq!(toad.remaining_str(), "oad");
+        toad.next();
+        assert_str_eq!(toad.consumed_str(), "to");
+        assert_str_eq!(toad.remaining_str(), "ad");
+        toad.next();
+        assert_str_eq!(toad.consumed_str(), "toa");
+        assert_str_eq!(toad.remaining_str(), "d");
+        toad.next();
+        assert_str_eq!(toad.consumed_str(), "toad");
+        assert_str_eq!(toad.remaining_str(), "");
+    }
+
+    #[test]
+    fn split_at_cursor() {
+        let mut toad = toad_iter();
+        assert_eq!(toad.split_at_cursor(), ("".to_owned(), "toad".to_owned()));
+        toad.next();
+        toad.next();
+        assert_eq!(toad.split_at_cursor(), ("to".to_owned(), "ad".to_owned()));
+        toad.seek(4);
+        assert_eq!(toad.split_at_cursor(), ("toad".to_owned(), "".to_owned()));
+    }
+
+    #[test]
+    fn from_symbol() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let sym: Symbol<String> = table.intern(&"toad".to_owned());
+        let mut toad: SymbolIterator<String> = sym.into();
+        assert_eq!(toad.position(), 0);
+        assert_eq!(toad.next(), Some('t'));
+        assert_str_eq!(toad.remaining_str(), "oad");
+    }
+
+    #[test]
+    fn lookahead() {
+        let mut toad = toad_iter();
+        assert_eq!(toad.peek_n(0), toad.peek());
+        assert_eq!(toad.peek_n(1), Some('o'));
+        assert_eq!(toad.peek_n(4), None);
+        assert_str_eq!(toad.peek_str(2), "to");
+        toad.next();
+        assert_str_eq!(toad.peek_str(10), "oad");
+        assert_str_eq!(toad.remaining_str(), "oad");
+    }
+
+    #[test]
+    fn outlives_a_stale_symbol() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let sym: Symbol<String> = table.intern(&"toad".to_owned());
+        let mut characters = SymbolIterator::new(sym);
+        characters.next();
+        table.clear();
+        assert_eq!(characters.position(), 1);
+        assert_eq!(characters.consumed_str(), "t");
+        assert_str_eq!(characters.to_string(), "\"t•oad\"");
+        characters.reset();
+        assert_eq!(characters.collect::<String>(), "toad");
+    }
 }
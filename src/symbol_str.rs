@@ -0,0 +1,60 @@
+use alloc::string::String;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+/// A [SymbolStr] is a view of the string behind a [Symbol], returned by
+/// [Symbol::as_str]. It derefs to `&str`, so it can be used anywhere a string
+/// slice is expected. The string is resolved once, when the view is created.
+pub struct SymbolStr<'a> {
+    value:  String,
+    // Ties the view to the Symbol it was created from.
+    source: PhantomData<&'a ()>,
+}
+
+impl<'a> SymbolStr<'a> {
+    pub(crate) fn new(value: String) -> Self {
+        Self {
+            value,
+            source: PhantomData,
+        }
+    }
+}
+
+impl<'a> Deref for SymbolStr<'a> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.value
+    }
+}
+
+impl<'a> AsRef<str> for SymbolStr<'a> {
+    fn as_ref(&self) -> &str {
+        &self.value
+    }
+}
+
+impl<'a> PartialEq<str> for SymbolStr<'a> {
+    fn eq(&self, other: &str) -> bool {
+        self.value == other
+    }
+}
+
+impl<'a, 'b> PartialEq<&'b str> for SymbolStr<'a> {
+    fn eq(&self, other: &&'b str) -> bool {
+        self.value == *other
+    }
+}
+
+impl<'a> fmt::Display for SymbolStr<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl<'a> fmt::Debug for SymbolStr<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.value)
+    }
+}
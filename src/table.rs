@@ -1,13 +1,32 @@
-use std::any::TypeId;
-use std::cell::RefCell;
-use std::rc::Rc;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::format;
+use alloc::rc::Rc;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::any::TypeId;
+use core::cell::{Cell, Ref, RefCell};
+use core::ops::Range;
+use core::ptr;
 
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::de::Error;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use typed_ids::SerialU64;
 
+#[cfg(feature = "serde")]
+use super::type_registry;
+#[cfg(feature = "std")]
+use super::{encoding, ArrayInterner, DecodeErr};
 use super::{
-    ArrayInterner, Internable, Interner, InternerFlavor, ResolutionErr, Resolvable, Symbol,
-    TableMismatchErr,
+    FrozenSymbolTable, InternErr, Internable, Interner, InternerFlavor, LazyInterner,
+    ResolutionErr, Resolvable, Symbol, SymbolRemap, SymbolTableBuilder, TableMismatchErr,
+    TableSnapshot, TableStats,
 };
+use crate::errors::parse_resolved;
+use crate::flavor::FromInterner;
 
 /// A [SymbolTable] allows you to store items according to their
 /// [String] representation in a lookup table. The lookup table
@@ -20,21 +39,80 @@ use super::{
 pub struct SymbolTable {
     // What if I pass the type ID into the interner?
     // Map the string to the typeID provided.
-    interner: Rc<RefCell<dyn Interner>>,
+    interner:   Rc<RefCell<dyn Interner>>,
+    // Counts how many times the table has been cleared or drained, so
+    // Symbols issued before then can be recognized as stale.
+    generation: Rc<Cell<u64>>,
+    // Counts interns which found their string already stored, and those
+    // which had to store it. Shared between clones, like the interner.
+    counts:     Rc<InternCounts>,
+}
+
+#[derive(Default)]
+struct InternCounts {
+    hits:   Cell<u64>,
+    misses: Cell<u64>,
 }
 
 impl SymbolTable {
     pub fn new(flavor: InternerFlavor) -> Self {
-        match flavor {
-            InternerFlavor::Array => Self::from(ArrayInterner::new()),
-        }
+        flavor.build()
+    }
+
+    /// [with_interner] builds a table backed by any [Interner], and is the
+    /// extension point for custom backends which aren't an [InternerFlavor].
+    /// It is equivalent to [SymbolTable::from].
+    pub fn with_interner<I: Interner + 'static>(interner: I) -> Self {
+        Self::from(interner)
+    }
+
+    /// [with_lazy_interner] builds a table backed by a [LazyInterner], which
+    /// fetches strings it hasn't cached from an external store through
+    /// `resolver`.
+    pub fn with_lazy_interner(
+        resolver: impl Fn(SerialU64<()>) -> Option<String> + 'static,
+    ) -> Self {
+        Self::from(LazyInterner::new(resolver))
+    }
+
+    /// [builder] returns a [SymbolTableBuilder], for configuring
+    /// a table with more options than [new] offers.
+    pub fn builder() -> SymbolTableBuilder {
+        SymbolTableBuilder::new()
+    }
+
+    /// [from_iter_with_symbols] builds a new [ArrayInterner]-backed table from
+    /// the items, returning it along with their [Symbol]s in input order.
+    /// Every returned Symbol is bound to the returned table.
+    pub fn from_iter_with_symbols<T: Internable + 'static, I: IntoIterator<Item = T>>(
+        items: I,
+    ) -> (SymbolTable, Vec<Symbol<T>>) {
+        let mut table = Self::new(InternerFlavor::Array);
+        let syms = table.intern_all(items);
+        (table, syms)
     }
 
     /// The [intern] function takes any object which can be converted
     /// to and from a [String], and interns it into the table. The resulting
     /// [Symbol] is unique if and only if no other item with the same type
     /// has already been stored in the table.
+    ///
+    /// # Panics
+    ///
+    /// [intern] panics if the item's string is new to the table, but the
+    /// interner has run out of ids to issue. Use [try_intern] to handle that
+    /// case instead.
     pub fn intern<T: Internable>(&mut self, item: &T) -> Symbol<T> {
+        match self.try_intern(item) {
+            Ok(sym) => sym,
+            Err(err) => panic!("failed to intern: {}", err),
+        }
+    }
+
+    /// [try_intern] is like [intern], but returns an [InternErr] instead of
+    /// panicking when the interner can't issue an id for a new string. A
+    /// failed intern leaves the table unchanged.
+    pub fn try_intern<T: Internable>(&mut self, item: &T) -> Result<Symbol<T>, InternErr> {
         // • Take this item and convert it into a string.
         let str_repr: String = item.as_ref().to_string();
         // • Fetch the type id, which we'll need to differentiate
@@ -42,31 +120,411 @@ impl SymbolTable {
         let typ_id = TypeId::of::<T>();
         // • Now that we have both the Type Id and the String representation,
         //   we can intern the item in the data structure.
-        let erased_id = self.interner.borrow_mut().intern(str_repr, typ_id);
+        let erased_id = self.intern_counted(&mut *self.interner.borrow_mut(), str_repr, typ_id)?;
         // • Now that we have the id of the entry, we need to convert
         //   this into a Symbol and increase the strength of the typing.
+        Ok(self.issue_symbol(erased_id))
+    }
+
+    /// [intern_reserve] is like [intern], but hints that about `extra` more
+    /// bytes of string data are about to be interned, so interners which
+    /// store strings contiguously, like the [BufferInterner], can grow their
+    /// storage once instead of repeatedly. It is purely a performance hint.
+    pub fn intern_reserve<T: Internable>(&mut self, item: &T, extra: usize) -> Symbol<T> {
+        let str_repr: String = item.as_ref().to_string();
+        let typ_id = TypeId::of::<T>();
+        let mut interner = self.interner.borrow_mut();
+        let before = interner.len();
+        let erased_id = expect_id(interner.intern_reserve(str_repr, typ_id, extra));
+        self.record_intern(interner.len() > before);
+        self.issue_symbol(erased_id)
+    }
+
+    /// [intern_with_status] is like [intern], but also returns true if the
+    /// item's string was newly added to the table, or false if it was already
+    /// stored, even if only under another type.
+    pub fn intern_with_status<T: Internable>(&mut self, item: &T) -> (Symbol<T>, bool) {
+        let str_repr: String = item.as_ref().to_string();
+        let typ_id = TypeId::of::<T>();
+        let result = self
+            .interner
+            .borrow_mut()
+            .intern_with_status(str_repr, typ_id);
+        let (erased_id, is_new) = result.unwrap_or_else(|err| panic!("failed to intern: {}", err));
+        self.record_intern(is_new);
+        (self.issue_symbol(erased_id), is_new)
+    }
+
+    /// [intern_str] interns a borrowed string as a `T`. If the string has
+    /// already been interned as a `T`, its [Symbol] is returned without
+    /// allocating a [String], for interners which can search with a borrowed
+    /// string; one is only allocated when the table has to store something
+    /// new. Interners which transform strings before storing them, like the
+    /// [CaseInsensitiveInterner] and [SuffixInterner], allocate the
+    /// transformed string on every lookup.
+    pub fn intern_str<T: Internable + 'static>(&mut self, s: &str) -> Symbol<T> {
+        let typ_id = TypeId::of::<T>();
+        // • Look for an existing entry first, which doesn't allocate.
+        let existing = self.interner.borrow().get_interned_str(s, typ_id);
+        let erased_id = match existing {
+            // • A hit still counts as a use, for interners which evict.
+            Some(id) => {
+                self.interner.borrow_mut().touch(id);
+                self.record_intern(false);
+                id
+            }
+            // • Only allocate when inserting something new.
+            None => {
+                let mut interner = self.interner.borrow_mut();
+                expect_id(self.intern_counted(&mut *interner, s.to_string(), typ_id))
+            }
+        };
+        self.issue_symbol(erased_id)
+    }
+
+    /// [get_or_intern] returns the [Symbol] for the string if it has already
+    /// been interned as a `T`, and interns it otherwise. It is a synonym for
+    /// [intern_str], for callers who think of it as a lookup.
+    pub fn get_or_intern<T: Internable + 'static>(&mut self, val: &str) -> Symbol<T> {
+        self.intern_str(val)
+    }
+
+    /// [get_or_intern_default] is like [get_or_intern], but falls back to
+    /// interning the string of `T::default()` when `val` can't be parsed back
+    /// into a `T`, so the returned [Symbol] always resolves.
+    pub fn get_or_intern_default<T: Internable + Default + 'static>(
+        &mut self,
+        val: &str,
+    ) -> Symbol<T> {
+        // • If the string is already interned as a `T`, return its Symbol.
+        if let Some(sym) = self.get_interned(val) {
+            return sym;
+        }
+        // • Otherwise, only intern strings which parse back into a `T`.
+        if T::try_from(val.to_string()).is_ok() {
+            return self.intern_str(val);
+        }
+        self.intern(&T::default())
+    }
+
+    /// [from_token] reads a token written by [Symbol::to_token], interning its
+    /// string part as a `T` in this table. The id part is ignored, since the
+    /// token may come from another table or process, so the returned
+    /// [Symbol] is bound to this table. It returns [None] if the token isn't
+    /// of the form `"<id>:<string>"`. A token with an empty string part is
+    /// only accepted if `""` is already interned as a `T` here, since it
+    /// could otherwise be a mangled token.
+    pub fn from_token<T: Internable + 'static>(&mut self, token: &str) -> Option<Symbol<T>> {
+        let (id, value) = token.split_once(':')?;
+        id.parse::<u64>().ok()?;
+        if value.is_empty() {
+            return self.get_interned::<T, _>(value);
+        }
+        Some(self.intern_str(value))
+    }
+
+    /// [intern_trimmed] is like [intern_str], but strips leading and trailing
+    /// whitespace first, so `" toad "` and `"toad"` share a [Symbol].
+    pub fn intern_trimmed<T: Internable + 'static>(&mut self, s: &str) -> Symbol<T> {
+        self.intern_str(s.trim())
+    }
+
+    /// [intern_normalized] is like [intern_trimmed], but also collapses each
+    /// run of whitespace inside the string into a single space, so
+    /// `"spotted \t toad"` and `"spotted toad"` share a [Symbol].
+    pub fn intern_normalized<T: Internable + 'static>(&mut self, s: &str) -> Symbol<T> {
+        let normalized = s.split_whitespace().collect::<Vec<_>>().join(" ");
+        self.intern_str(&normalized)
+    }
+
+    /// [intern_as] interns the string as both a `T1` and a `T2`, returning
+    /// a [Symbol] of each type. The string is only stored once, so both
+    /// Symbols share an id, though they remain distinct typed handles. For
+    /// more than two types, use the [intern_as!] macro.
+    pub fn intern_as<T1: Internable + 'static, T2: Internable + 'static>(
+        &mut self,
+        s: &str,
+    ) -> (Symbol<T1>, Symbol<T2>) {
+        let mut interner = self.interner.borrow_mut();
+        let first =
+            expect_id(self.intern_counted(&mut *interner, s.to_string(), TypeId::of::<T1>()));
+        let second =
+            expect_id(self.intern_counted(&mut *interner, s.to_string(), TypeId::of::<T2>()));
+        (self.issue_symbol(first), self.issue_symbol(second))
+    }
+
+    /// [intern_dyn] interns the string under a [TypeId] known only at
+    /// runtime, returning the untyped id. The string is still bucketed by
+    /// type, so interning it as a `T` later shares its cell. Use
+    /// [symbol_from_raw] to turn the id into a [Symbol] once the type is
+    /// known statically.
+    pub fn intern_dyn(&mut self, val: String, typ: TypeId) -> SerialU64<()> {
+        let mut interner = self.interner.borrow_mut();
+        expect_id(self.intern_counted(&mut *interner, val, typ))
+    }
+
+    /// [intern_chars] collects the chars into a [String] and interns it as
+    /// a `T`, for inputs which arrive one char at a time. An empty iterator
+    /// interns the empty string.
+    pub fn intern_chars<T: Internable + 'static>(
+        &mut self,
+        chars: impl Iterator<Item = char>,
+    ) -> Symbol<T> {
+        let str_repr: String = chars.collect();
+        let typ_id = TypeId::of::<T>();
+        let erased_id =
+            expect_id(self.intern_counted(&mut *self.interner.borrow_mut(), str_repr, typ_id));
+        self.issue_symbol(erased_id)
+    }
+
+    /// [intern_all] interns every item in the batch, returning their [Symbol]s
+    /// in the same order as the input. The interner is only borrowed once for
+    /// the whole batch.
+    pub fn intern_all<T: Internable, I: IntoIterator<Item = T>>(
+        &mut self,
+        items: I,
+    ) -> Vec<Symbol<T>> {
+        let typ_id = TypeId::of::<T>();
+        let mut interner = self.interner.borrow_mut();
+        items
+            .into_iter()
+            .map(|item| {
+                let str_repr: String = item.as_ref().to_string();
+                let erased_id = expect_id(self.intern_counted(&mut *interner, str_repr, typ_id));
+                self.issue_symbol(erased_id)
+            })
+            .collect()
+    }
+
+    /// [par_intern_all] is a parallel [intern_all], for loading large
+    /// batches with many duplicates. The batch is converted to strings and
+    /// de-duplicated in parallel, then the unique strings are interned under a
+    /// single borrow, in the order they first appear. The result is the same
+    /// as [intern_all]: the [Symbol]s are in input order, with the same ids,
+    /// as long as interning an already stored string changes nothing but its
+    /// types. That doesn't hold for interners which evict, like the
+    /// [BoundedInterner], whose evictions depend on the order of every
+    /// intern, duplicates included, so when [Interner::evicts] says so, the
+    /// batch is interned serially with [intern_all] instead.
+    #[cfg(feature = "rayon")]
+    pub fn par_intern_all<T: Internable + Sync + 'static>(
+        &mut self,
+        items: Vec<T>,
+    ) -> Vec<Symbol<T>> {
+        if self.interner.borrow().evicts() {
+            return self.intern_all(items);
+        }
+        let typ_id = TypeId::of::<T>();
+        // • Convert every item into a string.
+        let mut strings: Vec<String> = items
+            .par_iter()
+            .map(|item| item.as_ref().to_string())
+            .collect();
+        // • Sort the positions by their string, so duplicates end up in
+        //   runs. Ties are broken by position, so each run starts with
+        //   the string's first appearance.
+        let mut order: Vec<usize> = (0..strings.len()).collect();
+        order.par_sort_unstable_by(|a, b| strings[*a].cmp(&strings[*b]).then(a.cmp(b)));
+        let mut runs: Vec<&[usize]> = order.chunk_by(|a, b| strings[*a] == strings[*b]).collect();
+        // • Intern each unique string in order of first appearance, just
+        //   as intern_all would, and hand its id to every position in the run.
+        runs.par_sort_unstable_by_key(|run| run[0]);
+        let mut ids = vec![None; strings.len()];
+        let mut interner = self.interner.borrow_mut();
+        for run in runs {
+            let str_repr = core::mem::take(&mut strings[run[0]]);
+            let erased_id = expect_id(self.intern_counted(&mut *interner, str_repr, typ_id));
+            // • The rest of the run would have found the string stored.
+            for _ in 1..run.len() {
+                self.record_intern(false);
+            }
+            for position in run {
+                ids[*position] = Some(erased_id);
+            }
+        }
+        // • Every position belongs to exactly one run.
+        ids.into_iter()
+            .map(|id| self.issue_symbol(id.expect("every position is in a run")))
+            .collect()
+    }
+
+    /// [concat] joins the strings of two [Symbol]s with `sep`, and interns
+    /// the result as a `T`. Both Symbols must originate from this table, and
+    /// still resolve; otherwise, a [ResolutionErr] is returned and nothing
+    /// is interned.
+    pub fn concat<T: Internable + 'static>(
+        &mut self,
+        a: &Symbol<T>,
+        b: &Symbol<T>,
+        sep: &str,
+    ) -> Result<Symbol<T>, ResolutionErr<T>> {
+        // • Both Symbols must come from this table.
+        self.check_origin(a)?;
+        self.check_origin(b)?;
+        let mut interner = self.interner.borrow_mut();
+        // • Recover both strings, then stitch them together.
+        let first = interner
+            .resolve(a.erase_type())
+            .ok_or(ResolutionErr::UnknownIdErr)?;
+        let second = interner
+            .resolve(b.erase_type())
+            .ok_or(ResolutionErr::UnknownIdErr)?;
+        let joined = [first, second].join(sep);
+        let erased_id = expect_id(self.intern_counted(&mut *interner, joined, TypeId::of::<T>()));
+        self.to_typed_symbol(erased_id)
+    }
+
+    /// [map_symbol] resolves the [Symbol], transforms its string with `f`,
+    /// and interns the result back into this table as a `T`. While `f` runs,
+    /// the table is only borrowed for reading, so `f` may resolve other
+    /// Symbols. It fails in the same cases as [with_resolved].
+    pub fn map_symbol<T: Internable + 'static>(
+        &mut self,
+        sym: &Symbol<T>,
+        f: impl FnOnce(&str) -> String,
+    ) -> Result<Symbol<T>, ResolutionErr<T>> {
+        // • Resolve and transform first, releasing the read borrow
+        //   before the table is borrowed for writing.
+        let mapped = self.with_resolved(sym, f)?;
+        // • Then intern the result under the same type.
+        let erased_id = expect_id(self.intern_counted(
+            &mut *self.interner.borrow_mut(),
+            mapped,
+            TypeId::of::<T>(),
+        ));
         self.to_typed_symbol(erased_id)
     }
 
+    /// [to_lowercase] interns the lowercase form of the [Symbol]'s string as
+    /// a `T`, using Unicode case mapping. The original Symbol stays valid. It
+    /// fails in the same cases as [map_symbol].
+    pub fn to_lowercase<T: Internable + 'static>(
+        &mut self,
+        sym: &Symbol<T>,
+    ) -> Result<Symbol<T>, ResolutionErr<T>> {
+        self.map_symbol(sym, str::to_lowercase)
+    }
+
+    /// [to_uppercase] interns the uppercase form of the [Symbol]'s string as
+    /// a `T`, using Unicode case mapping. The original Symbol stays valid. It
+    /// fails in the same cases as [map_symbol].
+    pub fn to_uppercase<T: Internable + 'static>(
+        &mut self,
+        sym: &Symbol<T>,
+    ) -> Result<Symbol<T>, ResolutionErr<T>> {
+        self.map_symbol(sym, str::to_uppercase)
+    }
+
+    /// [replace] interns the [Symbol]'s string with every match of `from`
+    /// replaced by `to`, as with [str::replace]. The original Symbol stays
+    /// valid, and if nothing matched, the result is equal to it. It fails in
+    /// the same cases as [map_symbol].
+    pub fn replace<T: Internable + 'static>(
+        &mut self,
+        sym: &Symbol<T>,
+        from: &str,
+        to: &str,
+    ) -> Result<Symbol<T>, ResolutionErr<T>> {
+        self.map_symbol(sym, |val| val.replace(from, to))
+    }
+
+    /// [intern_path] interns every prefix of `path` which ends just before a
+    /// `sep`, followed by the whole path, returning their [Symbol]s from the
+    /// shortest to the longest. For example, `"a/b/c"` interns `"a"`, `"a/b"`,
+    /// and `"a/b/c"`. A leading separator would produce an empty prefix, which
+    /// is skipped, so `"/usr/lib"` interns `"/usr"` and `"/usr/lib"`.
+    pub fn intern_path<T: Internable + 'static>(
+        &mut self,
+        path: &str,
+        sep: char,
+    ) -> Vec<Symbol<T>> {
+        let typ_id = TypeId::of::<T>();
+        let mut interner = self.interner.borrow_mut();
+        path.match_indices(sep)
+            .map(|(end, _)| &path[..end])
+            .filter(|prefix| !prefix.is_empty())
+            .chain(core::iter::once(path))
+            .map(|prefix| {
+                let erased_id =
+                    expect_id(self.intern_counted(&mut *interner, prefix.to_string(), typ_id));
+                self.issue_symbol(erased_id)
+            })
+            .collect()
+    }
+
     /// Resolve returns the object that was originally stored in the table.
-    /// If this [Symbol] was created by a [SymbolTable] other than `self`, then
-    /// [resolve] returns a [ResolutionErr]. Otherwise, a valid value will be
-    /// returned.
+    /// If this [Symbol] was created by a [SymbolTable] other than `self`, or
+    /// its id is unknown to the table, then [resolve] returns a
+    /// [ResolutionErr]. Otherwise, a valid value will be returned.
     pub fn resolve<T: Internable + 'static>(&self, sym: &Symbol<T>) -> Result<T, ResolutionErr<T>> {
         // • Before we do anything else, we need to confirm this Symbol
-        //   originates from this table. Check the pointer of this table
-        //   makes the memory location of the Symbol's table.
-        let table_addr = self.addr();
-        let sym_addr = sym.origin();
-        if table_addr != sym_addr {
-            let err = ResolutionErr::from(TableMismatchErr::new(table_addr, sym_addr));
-            return Err(err);
-        }
+        //   originates from this table.
+        self.check_origin(sym)?;
         // • Convert the Symbol back into an Id.
         let id = sym.erase_type();
-        let resolution = self.interner.borrow().resolve(id);
-        // TODO: Don't throw away the result. Capture it in the ParseErr.
-        T::try_from(resolution).map_err(|_| ResolutionErr::ParseErr)
+        let interner = self.interner.borrow();
+        let resolution = interner
+            .resolve_ref(id)
+            .ok_or(ResolutionErr::UnknownIdErr)?;
+        parse_resolved(&resolution)
+    }
+
+    /// [resolve_rc] is like [resolve], but returns the Symbol's string as an
+    /// [Rc<str>]. With an [RcStrInterner], every call returns a clone of the
+    /// same [Rc], so nothing is allocated; other interners allocate a new one.
+    pub fn resolve_rc<T: Internable + 'static>(
+        &self,
+        sym: &Symbol<T>,
+    ) -> Result<Rc<str>, ResolutionErr<T>> {
+        self.check_origin(sym)?;
+        self.interner
+            .borrow()
+            .resolve_rc(sym.erase_type())
+            .ok_or(ResolutionErr::UnknownIdErr)
+    }
+
+    /// [resolve_dyn] returns the string stored under the untyped id, without
+    /// checking which types it was interned as. It pairs with [intern_dyn].
+    pub fn resolve_dyn(&self, id: SerialU64<()>) -> Option<String> {
+        self.interner.borrow().resolve(id)
+    }
+
+    /// [resolve_many] resolves every [Symbol] in the batch, returning the
+    /// results in the same order as the input. The interner is only borrowed
+    /// once for the whole batch. Each Symbol succeeds or fails on its own, so
+    /// a Symbol from another table only produces an error in its own slot.
+    pub fn resolve_many<T: Internable + 'static>(
+        &self,
+        syms: &[Symbol<T>],
+    ) -> Vec<Result<T, ResolutionErr<T>>> {
+        let interner = self.interner.borrow();
+        syms.iter()
+            .map(|sym| {
+                self.check_origin(sym)?;
+                let resolution = interner
+                    .resolve_ref(sym.erase_type())
+                    .ok_or(ResolutionErr::UnknownIdErr)?;
+                parse_resolved(&resolution)
+            })
+            .collect()
+    }
+
+    /// [with_resolved] lends the interned string to `f` and returns its
+    /// result. Unlike [resolve], the string is not parsed into a `T`, and
+    /// interners which store strings contiguously lend them without cloning.
+    /// It fails in the same cases as [resolve], except for parsing.
+    pub fn with_resolved<T: Internable + 'static, R>(
+        &self,
+        sym: &Symbol<T>,
+        f: impl FnOnce(&str) -> R,
+    ) -> Result<R, ResolutionErr<T>> {
+        self.check_origin(sym)?;
+        let id = sym.erase_type();
+        let interner = self.interner.borrow();
+        let resolution = interner
+            .resolve_ref(id)
+            .ok_or(ResolutionErr::UnknownIdErr)?;
+        Ok(f(&resolution))
     }
 
     pub fn get_interned<T: Internable + 'static, S: AsRef<str>>(
@@ -81,20 +539,495 @@ impl SymbolTable {
         // • Query the store, asking about this string and type.
         let id = self.interner.borrow().get_interned(str_repr, typ_id)?;
         // Convert the id into a Symbol.
-        Some(self.to_typed_symbol(id))
+        self.to_typed_symbol(id).ok()
+    }
+
+    /// [symbol_from_raw] rebuilds a [Symbol] from the id returned by
+    /// [Symbol::raw], bound to this table. It returns [None] unless the id
+    /// refers to a string in this table which was interned as a `T`.
+    pub fn symbol_from_raw<T: Internable + 'static>(&self, raw: u64) -> Option<Symbol<T>> {
+        let id = SerialU64::<()>::try_from(raw).ok()?;
+        let interner = self.interner.borrow();
+        // • The id must resolve, and looking its string back up under `T`
+        //   must land on the same id. This rejects the sentinel, removed
+        //   ids, and strings which were never interned as a `T`.
+        let val = interner.resolve(id)?;
+        let found = interner.get_interned(val, TypeId::of::<T>())?;
+        if found != id {
+            return None;
+        }
+        self.to_typed_symbol(id).ok()
+    }
+
+    /// [types_for] lists the [TypeId] of every type the string has been
+    /// interned as, which helps track down Symbols of the wrong type. It
+    /// returns nothing if the string isn't stored in the table.
+    pub fn types_for<S: AsRef<str>>(&self, val: S) -> Vec<TypeId> {
+        self.interner.borrow().types_for(val.as_ref())
     }
 
     pub fn has_interned<T: Internable + 'static, S: AsRef<str>>(&self, val: S) -> bool {
         self.get_interned::<T, S>(val).is_some()
     }
 
-    fn to_typed_symbol<T: Internable>(&self, id: SerialU64<()>) -> Symbol<T> {
-        let upcast_id = self.upcast(id);
-        self.id_as_symbol(upcast_id)
+    /// [get_interned_any] returns the untyped id of the string if it has been
+    /// interned under any type, ignoring the type check of [get_interned].
+    /// Use [symbol_from_raw] to turn the id into a [Symbol] of a type the
+    /// string was actually interned as.
+    pub fn get_interned_any<S: AsRef<str>>(&self, val: S) -> Option<SerialU64<()>> {
+        self.interner.borrow().get_interned_any(val.as_ref())
+    }
+
+    /// [has_interned_any] reports whether the string has been interned under
+    /// any type at all.
+    pub fn has_interned_any<S: AsRef<str>>(&self, val: S) -> bool {
+        self.get_interned_any(val).is_some()
+    }
+
+    /// [contains_id] reports whether the untyped id refers to a string in
+    /// this table, so a raw or deserialized id can be checked before it is
+    /// resolved. The sentinel id 0 and removed ids aren't contained.
+    pub fn contains_id(&self, id: SerialU64<()>) -> bool {
+        self.interner.borrow().contains_id(id)
+    }
+
+    /// [remove] detaches the type `T` from the string the [Symbol] refers
+    /// to, returning false if the Symbol isn't from this table or was already
+    /// removed. Once no types refer to the string, its value is freed, and
+    /// resolving any Symbol for it returns a [ResolutionErr] rather than a
+    /// stale value. The id is left behind as a tombstone, so it is never
+    /// reused and every other Symbol stays valid. While other types still
+    /// refer to the string, Symbols for it continue to resolve.
+    pub fn remove<T: Internable + 'static>(&mut self, sym: &Symbol<T>) -> bool {
+        if self.check_origin(sym).is_err() {
+            return false;
+        }
+        let typ_id = TypeId::of::<T>();
+        self.interner.borrow_mut().remove(sym.erase_type(), typ_id)
+    }
+
+    /// [clear] empties the table in place, as though it were just constructed.
+    /// [Symbol]s issued before the clear still refer to this table, but they
+    /// become stale: they fail to resolve with a [ResolutionErr::StaleSymbolErr],
+    /// even once new strings take over their ids.
+    pub fn clear(&mut self) {
+        self.interner.borrow_mut().clear();
+        self.advance_generation();
+    }
+
+    /// [clear_type] removes every string's association with `T`, as though
+    /// each `T` [Symbol] were passed to [remove]. Strings which other types
+    /// still refer to are kept, and their Symbols continue to resolve.
+    pub fn clear_type<T: 'static>(&mut self) {
+        let typ_id = TypeId::of::<T>();
+        let mut interner = self.interner.borrow_mut();
+        for (id, _, typs) in interner.entries() {
+            if typs.contains(&typ_id) {
+                interner.remove(id, typ_id);
+            }
+        }
+    }
+
+    /// [drain] empties the table, yielding every string it held in the order
+    /// they were first interned. Afterwards, the table is in the same state
+    /// as after [clear], so previously issued [Symbol]s are stale.
+    pub fn drain(&mut self) -> impl Iterator<Item = String> {
+        let strings = self.interner.borrow_mut().take_strings();
+        self.advance_generation();
+        strings.into_iter()
+    }
+
+    /// [to_bytes] writes the table's entries in a flat, length-prefixed
+    /// binary format, as a dependency-free alternative to serde.
+    ///
+    /// The output can only be read back by the same running process. Like
+    /// the serde format, the types of each entry are written as indices
+    /// which are assigned as the process runs, so another process, or a
+    /// later run of this one, can't recover them. It is suited to moving
+    /// tables between threads or caching them in memory, not to storage.
+    #[cfg(feature = "std")]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        encoding::encode(self.interner.borrow().entries())
+    }
+
+    /// [from_bytes] rebuilds a table from the output of [to_bytes]. Like a
+    /// deserialized table, it is backed by an [ArrayInterner] and keeps every
+    /// id, but Symbols issued by the original table won't resolve against it.
+    ///
+    /// Only bytes written by [to_bytes] in this same process can be read.
+    /// Malformed input fails with a [DecodeErr] rather than panicking, and
+    /// the table never holds more ids than the input declares.
+    #[cfg(feature = "std")]
+    pub fn from_bytes(bytes: &[u8]) -> Result<SymbolTable, DecodeErr> {
+        let entries = encoding::decode(bytes)?;
+        Ok(Self::from(ArrayInterner::from_entries(entries)))
+    }
+
+    /// [reserve] pre-allocates room for at least `additional` more strings,
+    /// avoiding repeated reallocation when interning a large batch. It is
+    /// purely a performance hint, and doesn't change the table's contents.
+    pub fn reserve(&mut self, additional: usize) {
+        self.interner.borrow_mut().reserve(additional);
+    }
+
+    /// [shrink_to_fit] releases any excess capacity held by the table, such as
+    /// after interning a large batch and removing much of it. Ids and
+    /// resolution are unaffected, so every [Symbol] remains valid.
+    pub fn shrink_to_fit(&mut self) {
+        self.interner.borrow_mut().shrink_to_fit();
+    }
+
+    /// [merge] interns every string stored in `other` into this table, under
+    /// each of the types it was interned with. Since the two tables issue ids
+    /// independently, it returns a [SymbolRemap] for translating Symbols from
+    /// `other` into Symbols for this table. `other` is left unchanged.
+    pub fn merge(&mut self, other: &SymbolTable) -> SymbolRemap {
+        // • Copy the entries out first, in case `other` shares
+        //   this table's interner.
+        let entries = other.interner.borrow().entries();
+        let mut interner = self.interner.borrow_mut();
+        let mut ids = BTreeMap::new();
+        for (old_id, value, typs) in entries {
+            // • Every type shares one string, so interning each type
+            //   lands on the same new id.
+            for typ in typs {
+                let new_id = expect_id(self.intern_counted(&mut *interner, value.clone(), typ));
+                ids.insert(old_id.get(), new_id.get());
+            }
+        }
+        drop(interner);
+        SymbolRemap::new(other.clone(), self.clone(), ids)
+    }
+
+    /// [compact] renumbers the table's strings densely, dropping the ids left
+    /// behind by [remove], and returns a [SymbolRemap] from the old ids to
+    /// the new ones. Every [Symbol] issued before the compaction becomes
+    /// stale, just as after a [clear]; translate it with the remap to keep
+    /// using it. Strings keep their relative order.
+    pub fn compact(&mut self) -> SymbolRemap {
+        // • Copy out the live entries, then re-intern them from scratch,
+        //   in id order, so they are issued consecutive ids.
+        let entries = self.interner.borrow().entries();
+        let mut interner = self.interner.borrow_mut();
+        interner.clear();
+        let mut ids = BTreeMap::new();
+        for (old_id, value, typs) in entries {
+            for typ in typs {
+                let new_id = expect_id(interner.intern(value.clone(), typ));
+                ids.insert(old_id.get(), new_id.get());
+            }
+        }
+        drop(interner);
+        // • The remap only accepts Symbols from before the compaction,
+        //   so it must be made before the generation moves on.
+        let remap = SymbolRemap::new(self.clone(), self.clone(), ids);
+        self.advance_generation();
+        remap
+    }
+
+    /// [content_eq] reports whether both tables hold the same strings, each
+    /// interned under the same types, regardless of the ids they were issued.
+    /// Tables built from the same inputs in different orders, or with
+    /// different [InternerFlavor]s, are equal by content.
+    pub fn content_eq(&self, other: &SymbolTable) -> bool {
+        self.content() == other.content()
+    }
+
+    // Maps each stored string to the set of types it was interned under.
+    fn content(&self) -> BTreeMap<String, BTreeSet<TypeId>> {
+        self.interner
+            .borrow()
+            .entries()
+            .into_iter()
+            .map(|(_, value, typs)| (value, typs.into_iter().collect()))
+            .collect()
+    }
+
+    /// [freeze] consumes the table, returning a read-only copy that resolves
+    /// without any [RefCell] borrow overhead. Use it once a table is fully
+    /// built and will only be read from. Symbols issued by this table remain
+    /// valid against the frozen copy.
+    pub fn freeze(self) -> FrozenSymbolTable {
+        FrozenSymbolTable::new(self)
+    }
+
+    /// [snapshot] copies the table's current contents into a [TableSnapshot],
+    /// a read-only view which can be shared across threads. The table can
+    /// keep interning without affecting the snapshot.
+    pub fn snapshot(&self) -> TableSnapshot {
+        TableSnapshot::new(self)
+    }
+
+    /// [sort_symbols] sorts the Symbols alphabetically by their interned
+    /// strings, as with [Symbol::lexical_cmp]. Each Symbol is only resolved
+    /// once, rather than on every comparison.
+    pub fn sort_symbols<T: Internable + 'static>(&self, syms: &mut [Symbol<T>]) {
+        syms.sort_by_cached_key(|sym| sym.raw_value());
+    }
+
+    /// [entries] walks every string interned as a `T`, paired with its
+    /// [Symbol], in the order they were first interned. Strings which were
+    /// only interned as other types are skipped.
+    pub fn entries<T: Internable + 'static>(&self) -> impl Iterator<Item = (Symbol<T>, String)> {
+        let typ_id = TypeId::of::<T>();
+        let entries: Vec<(Symbol<T>, String)> = self
+            .interner
+            .borrow()
+            .entries()
+            .into_iter()
+            .filter(|(_, _, typs)| typs.contains(&typ_id))
+            .filter_map(|(id, value, _)| Some((self.to_typed_symbol(id).ok()?, value)))
+            .collect();
+        entries.into_iter()
+    }
+
+    /// [symbols] walks the [Symbol] of every string interned as a `T`, in
+    /// the order they were first interned. Like [entries], strings which
+    /// were only interned as other types are skipped.
+    pub fn symbols<T: Internable + 'static>(&self) -> impl Iterator<Item = Symbol<T>> {
+        self.entries::<T>().map(|(sym, _)| sym)
+    }
+
+    /// [with_prefix] returns the [Symbol] of every string interned as a `T`
+    /// which starts with `prefix`, in id order. It scans every entry.
+    pub fn with_prefix<T: Internable + 'static>(&self, prefix: &str) -> Vec<Symbol<T>> {
+        self.entries::<T>()
+            .filter(|(_, value)| value.starts_with(prefix))
+            .map(|(sym, _)| sym)
+            .collect()
+    }
+
+    /// [with_suffix] returns the [Symbol] of every string interned as a `T`
+    /// which ends with `suffix`, in id order. It scans every entry, unless
+    /// the table was built with [InternerFlavor::SuffixIndex], which keeps
+    /// its strings indexed by suffix.
+    pub fn with_suffix<T: Internable + 'static>(&self, suffix: &str) -> Vec<Symbol<T>> {
+        let ids = self
+            .interner
+            .borrow()
+            .with_suffix(suffix, TypeId::of::<T>());
+        ids.into_iter().map(|id| self.issue_symbol(id)).collect()
+    }
+
+    /// [symbols_in_range] returns the [Symbol] of every string interned as a
+    /// `T` whose id falls within `range`, in id order. Since ids are issued
+    /// in sequence, a range of ids is a window of insertion time: everything
+    /// interned after a checkpoint `sym` lies in `sym.raw() + 1..u64::MAX`.
+    pub fn symbols_in_range<T: Internable + 'static>(&self, range: Range<u64>) -> Vec<Symbol<T>> {
+        self.entries::<T>()
+            .map(|(sym, _)| sym)
+            .filter(|sym| range.contains(&sym.raw()))
+            .collect()
+    }
+
+    /// [iter_strings] walks every distinct string stored in the table,
+    /// in the order they were first interned.
+    pub fn iter_strings(&self) -> impl Iterator<Item = String> {
+        self.interner.borrow().strings().into_iter()
+    }
+
+    /// [iter_strings_rev] walks every distinct string stored in the table,
+    /// most recently interned first. It is the mirror of [iter_strings].
+    pub fn iter_strings_rev(&self) -> impl Iterator<Item = String> {
+        self.interner.borrow().strings().into_iter().rev()
+    }
+
+    /// [iter_sorted] walks every distinct string stored in the table in
+    /// lexicographic order, comparing bytes like [str]'s [Ord].
+    pub fn iter_sorted(&self) -> impl Iterator<Item = String> {
+        self.interner.borrow().sorted_strings().into_iter()
+    }
+
+    /// [len] returns the number of distinct strings stored in the table.
+    /// A string interned under several types is only counted once.
+    pub fn len(&self) -> usize {
+        self.interner.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.interner.borrow().is_empty()
+    }
+
+    /// [stats] reports how many entries the table holds and how much
+    /// string data its interner stores, after compression.
+    pub fn stats(&self) -> TableStats {
+        self.interner.borrow().stats()
+    }
+
+    /// [intern_hits] counts the strings interned through this table which
+    /// were already stored, even if only under another type. Clones of the
+    /// table share the count, which isn't reset by [clear].
+    pub fn intern_hits(&self) -> u64 {
+        self.counts.hits.get()
+    }
+
+    /// [intern_misses] counts the strings interned through this table which
+    /// had to be newly stored. Together with [intern_hits], it shows how
+    /// often interning finds work already done.
+    pub fn intern_misses(&self) -> u64 {
+        self.counts.misses.get()
+    }
+
+    /// [check_invariants] verifies the interner upholds what the table relies
+    /// on: the sentinel id 0 holds nothing, every entry resolves to its own
+    /// string, no two live ids share a string, and looking each string back
+    /// up finds its id. It is a debugging and property testing aid for
+    /// catching corruption in custom interners, and costs O(n) lookups. The
+    /// first violation found is described in the error.
+    pub fn check_invariants(&self) -> Result<(), String> {
+        let interner = self.interner.borrow();
+        // • The sentinel must never resolve.
+        let sentinel = SerialU64::<()>::try_from(0).unwrap();
+        if let Some(val) = interner.resolve(sentinel) {
+            return Err(format!("the sentinel id 0 resolves to {:?}", val));
+        }
+        let entries = interner.entries();
+        if entries.len() != interner.len() {
+            return Err(format!(
+                "the interner holds {} entries, but reports a len of {}",
+                entries.len(),
+                interner.len()
+            ));
+        }
+        let mut seen = BTreeSet::new();
+        for (id, val, typs) in &entries {
+            // • Every entry must resolve to its own string.
+            let resolved = interner.resolve(*id);
+            if resolved.as_ref() != Some(val) {
+                return Err(format!(
+                    "id {} holds {:?}, but resolves to {:?}",
+                    id, val, resolved
+                ));
+            }
+            // • No other live id may hold the same string.
+            if !seen.insert(val.as_str()) {
+                return Err(format!("{:?} is stored under more than one id", val));
+            }
+            // • Looking the string back up must land on the same id.
+            if interner.get_interned_any(val) != Some(*id) {
+                return Err(format!(
+                    "{:?} is stored under id {}, but isn't found there",
+                    val, id
+                ));
+            }
+            for typ in typs {
+                if interner.get_interned_str(val, *typ) != Some(*id) {
+                    return Err(format!(
+                        "{:?} is stored under id {} as {:?}, but isn't found there",
+                        val, id, typ
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// [owns] reports whether the [Symbol] was issued by this table since it
+    /// was last cleared, which are the checks [resolve] makes before anything
+    /// else. It says nothing about whether the Symbol's id still resolves.
+    pub fn owns<T: Internable + 'static>(&self, sym: &Symbol<T>) -> bool {
+        self.shares_interner(sym) && sym.generation() == self.generation.get()
+    }
+
+    /// [same_content] reports whether two [Symbol]s, perhaps of different
+    /// types, were issued for the same interned string. It compares their
+    /// ids in O(1), without resolving either. Symbols which don't both
+    /// originate from this table never share content.
+    pub fn same_content<A: Internable + 'static, B: Internable + 'static>(
+        &self,
+        a: &Symbol<A>,
+        b: &Symbol<B>,
+    ) -> bool {
+        self.owns(a) && self.owns(b) && a.erase_type() == b.erase_type()
+    }
+
+    /// [interner_ref] borrows the table's backing interner as the concrete
+    /// type `I`, for introspection beyond what [Interner] offers. It returns
+    /// [None] if the table isn't backed by an `I`. The table can't intern
+    /// while the borrow is held.
+    pub fn interner_ref<I: Interner + 'static>(&self) -> Option<Ref<'_, I>> {
+        Ref::filter_map(self.interner.borrow(), |interner| {
+            interner.as_any().downcast_ref::<I>()
+        })
+        .ok()
+    }
+
+    pub(crate) fn interner(&self) -> Ref<'_, dyn Interner> {
+        self.interner.borrow()
+    }
+
+    // Interns through the interner on a caller's behalf, counting whether
+    // the string was already stored.
+    fn intern_counted(
+        &self,
+        interner: &mut dyn Interner,
+        val: String,
+        typ: TypeId,
+    ) -> Result<SerialU64<()>, InternErr> {
+        let (id, is_new) = interner.intern_with_status(val, typ)?;
+        self.record_intern(is_new);
+        Ok(id)
+    }
+
+    fn record_intern(&self, is_new: bool) {
+        let count = if is_new {
+            &self.counts.misses
+        } else {
+            &self.counts.hits
+        };
+        count.set(count.get() + 1);
+    }
+
+    fn advance_generation(&self) {
+        self.generation.set(self.generation.get() + 1);
+    }
+
+    // Confirms the Symbol originates from this table by checking the pointer
+    // of this table matches the memory location of the Symbol's table.
+    // Reports whether the Symbol points at this table's interner, whether or
+    // not the table has been cleared since it was issued.
+    fn shares_interner<T: Internable + 'static>(&self, sym: &Symbol<T>) -> bool {
+        ptr::addr_eq(self.addr(), sym.origin())
+    }
+
+    fn check_origin<T: Internable + 'static>(
+        &self,
+        sym: &Symbol<T>,
+    ) -> Result<(), ResolutionErr<T>> {
+        if !self.shares_interner(sym) {
+            let err = ResolutionErr::from(TableMismatchErr::new(self.addr(), sym.origin()));
+            return Err(err);
+        }
+        // • The Symbol must also have been issued since the table
+        //   was last cleared.
+        if sym.generation() != self.generation.get() {
+            return Err(ResolutionErr::StaleSymbolErr);
+        }
+        Ok(())
+    }
+
+    // Issues a Symbol for an id the interner just handed out. The interner
+    // has already range-checked the id, and a typed id accepts the same
+    // range, so the conversion can't fail.
+    fn issue_symbol<T: Internable + 'static>(&self, id: SerialU64<()>) -> Symbol<T> {
+        match self.to_typed_symbol(id) {
+            Ok(sym) => sym,
+            Err(_) => unreachable!("the interner issued an out-of-range id {}", id),
+        }
+    }
+
+    fn to_typed_symbol<T: Internable + 'static>(
+        &self,
+        id: SerialU64<()>,
+    ) -> Result<Symbol<T>, ResolutionErr<T>> {
+        let upcast_id = self.upcast(id.get())?;
+        Ok(self.id_as_symbol(upcast_id))
     }
 
-    fn upcast<T: Internable>(&self, id: SerialU64<()>) -> SerialU64<T> {
-        SerialU64::<T>::try_from(id.get()).unwrap()
+    // An id which can't be typed is reported as unknown, rather than
+    // unwinding, since it can't refer to anything in the table.
+    fn upcast<T: Internable + 'static>(&self, raw: u64) -> Result<SerialU64<T>, ResolutionErr<T>> {
+        SerialU64::<T>::try_from(raw).map_err(|_| ResolutionErr::UnknownIdErr)
     }
 
     fn id_as_symbol<T: Internable>(&self, id: SerialU64<T>) -> Symbol<T> {
@@ -112,7 +1045,7 @@ impl SymbolTable {
 impl Resolvable for SymbolTable {
     // To implement resolve, we delegate the work to
     // the held interner.
-    fn resolve(&self, id: SerialU64<()>) -> String {
+    fn resolve(&self, id: SerialU64<()>) -> Option<String> {
         self.interner.borrow().resolve(id)
     }
 
@@ -121,6 +1054,10 @@ impl Resolvable for SymbolTable {
     fn addr(&self) -> *const (dyn Interner + 'static) {
         self.interner.as_ptr()
     }
+
+    fn generation(&self) -> u64 {
+        self.generation.get()
+    }
 }
 
 impl<T: Interner + 'static> From<T> for SymbolTable {
@@ -128,14 +1065,168 @@ impl<T: Interner + 'static> From<T> for SymbolTable {
         let cell = RefCell::new(interner);
         let ref_counter = Rc::new(cell);
         Self {
-            interner: ref_counter,
+            interner:   ref_counter,
+            generation: Rc::new(Cell::new(0)),
+            counts:     Rc::default(),
+        }
+    }
+}
+
+impl FromInterner for SymbolTable {
+    fn from_interner<I: Interner + Send + Sync + 'static>(interner: I) -> Self {
+        Self::from(interner)
+    }
+}
+
+/// Extending a [SymbolTable] interns every item in one batch, discarding the
+/// resulting [Symbol]s. They can be recovered later with [get_interned].
+impl<T: Internable + 'static> Extend<T> for SymbolTable {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.intern_all(iter);
+    }
+}
+
+/// Collecting into a [SymbolTable] builds an [ArrayInterner]-backed table
+/// holding every item. Use [from_iter_with_symbols] to keep the [Symbol]s.
+impl<T: Internable + 'static> FromIterator<T> for SymbolTable {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from_iter_with_symbols(iter).0
+    }
+}
+
+// A single table entry as it is written to disk.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct SerializedEntry {
+    id:    u64,
+    value: String,
+    typs:  Vec<u64>,
+}
+
+/// A [SymbolTable] is serialized as its entries in id order. The [TypeId]s
+/// of each entry are written as opaque indices which are only valid within
+/// the running program, so a table must be loaded by the same process that
+/// serialized it.
+#[cfg(feature = "serde")]
+impl Serialize for SymbolTable {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let entries: Vec<SerializedEntry> = self
+            .interner
+            .borrow()
+            .entries()
+            .into_iter()
+            .map(|(id, value, typs)| SerializedEntry {
+                id: id.get(),
+                value,
+                typs: typs.into_iter().map(type_registry::index_of).collect(),
+            })
+            .collect();
+        entries.serialize(serializer)
+    }
+}
+
+/// A deserialized [SymbolTable] is backed by an [ArrayInterner] and resolves
+/// every id to the same value as the table that was serialized. However, it is
+/// a new table: [Symbol]s issued before serialization remain bound to the
+/// address of their original table, and will not resolve against the reloaded
+/// one. Use [get_interned] to recover Symbols from the reloaded table.
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for SymbolTable {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let serialized = Vec::<SerializedEntry>::deserialize(deserializer)?;
+        let mut entries = Vec::with_capacity(serialized.len());
+        let mut last_id = 0;
+        for entry in serialized {
+            // • Ids must be strictly increasing, and id 0 is reserved
+            //   for the sentinel, so every entry lands in its own slot.
+            if entry.id <= last_id {
+                return Err(D::Error::custom(format!("out of order id {}", entry.id)));
+            }
+            last_id = entry.id;
+            let id = SerialU64::try_from(entry.id)
+                .map_err(|_| D::Error::custom(format!("invalid id {}", entry.id)))?;
+            let typs = entry
+                .typs
+                .into_iter()
+                .map(|index| {
+                    type_registry::type_at(index)
+                        .ok_or_else(|| D::Error::custom(format!("unknown type index {}", index)))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            entries.push((id, entry.value, typs));
         }
+        Ok(Self::from(ArrayInterner::from_entries(entries)))
     }
 }
 
+// Unwraps the id of a string the table interned on the caller's behalf.
+// Like [SymbolTable::intern], it panics if the interner has run out of ids.
+fn expect_id(result: Result<SerialU64<()>, InternErr>) -> SerialU64<()> {
+    result.unwrap_or_else(|err| panic!("failed to intern: {}", err))
+}
+
 #[cfg(test)]
-mod tests {
-    use super::{InternerFlavor, Symbol, SymbolTable};
+pub(crate) mod tests {
+    use std::any::{Any, TypeId};
+    use std::collections::BTreeSet;
+
+    use typed_ids::SerialU64;
+
+    use super::{
+        InternErr, Interner, InternerFlavor, ResolutionErr, Symbol, SymbolTable, TableStats,
+    };
+    use crate::{ArrayInterner, CaseInsensitiveInterner, RadixInterner};
+
+    struct Address(String);
+
+    impl AsRef<str> for Address {
+        fn as_ref(&self) -> &str {
+            &self.0
+        }
+    }
+
+    impl From<String> for Address {
+        fn from(val: String) -> Self {
+            Self(val)
+        }
+    }
+
+    // A Lowercase can be built from any string, but only
+    // lowercase strings can be parsed back into one. The
+    // tests of other modules share it.
+    #[derive(Default)]
+    pub(crate) struct Lowercase(pub(crate) String);
+
+    impl AsRef<str> for Lowercase {
+        fn as_ref(&self) -> &str {
+            &self.0
+        }
+    }
+
+    impl TryFrom<String> for Lowercase {
+        type Error = ();
+
+        fn try_from(val: String) -> Result<Self, ()> {
+            if val.chars().any(char::is_uppercase) {
+                return Err(());
+            }
+            Ok(Self(val))
+        }
+    }
+
+    struct Username(String);
+
+    impl AsRef<str> for Username {
+        fn as_ref(&self) -> &str {
+            &self.0
+        }
+    }
+
+    impl From<String> for Username {
+        fn from(val: String) -> Self {
+            Self(val)
+        }
+    }
 
     #[test]
     fn symbols_mismatch() {
@@ -179,4 +1270,1254 @@ mod tests {
         assert_eq!(true, table.has_interned::<String, _>("frog"));
         assert_eq!(false, table.has_interned::<String, _>("toad"));
     }
+
+    #[test]
+    fn has_string_only_for_interned_type() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let address = Address::from("alice".to_owned());
+        let _: Symbol<Address> = table.intern(&address);
+        assert!(table.has_interned::<Address, _>("alice"));
+        assert!(!table.has_interned::<Username, _>("alice"));
+        assert!(table.get_interned::<Username, _>("alice").is_none());
+    }
+
+    #[test]
+    fn unknown_id_is_an_error() {
+        let table = SymbolTable::new(InternerFlavor::Array);
+        let id = SerialU64::try_from(42).unwrap();
+        let sym: Symbol<String> = Symbol::new(id, table.clone());
+        assert_eq!(table.resolve(&sym), Err(ResolutionErr::UnknownIdErr));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let frog: Symbol<String> = table.intern(&"frog".to_owned());
+        let newt: Symbol<String> = table.intern(&"newt".to_owned());
+        let toad: Symbol<Address> = table.intern(&Address::from("toad".to_owned()));
+        table.remove(&newt);
+        let json = serde_json::to_string(&table).unwrap();
+        let loaded: SymbolTable = serde_json::from_str(&json).unwrap();
+
+        assert!(loaded.content_eq(&table));
+        assert!(loaded.has_interned::<String, _>("frog"));
+        assert!(loaded.has_interned::<Address, _>("toad"));
+        assert!(!loaded.has_interned::<Username, _>("toad"));
+        assert!(!loaded.has_interned::<String, _>("newt"));
+        assert_eq!(loaded.len(), 2);
+        let reloaded_frog: Symbol<String> = loaded.get_interned("frog").unwrap();
+        let reloaded_toad: Symbol<Address> = loaded.get_interned("toad").unwrap();
+        assert_eq!(reloaded_frog.id(), frog.id());
+        assert_eq!(reloaded_toad.id(), toad.id());
+        // Symbols from the original table are bound to its address.
+        assert!(loaded.resolve(&frog).is_err());
+    }
+
+    #[test]
+    fn len_counts_distinct_strings() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        assert_eq!(table.len(), 0);
+        assert!(table.is_empty());
+        let _: Symbol<String> = table.intern(&"frog".to_owned());
+        assert_eq!(table.len(), 1);
+        let _: Symbol<String> = table.intern(&"toad".to_owned());
+        assert_eq!(table.len(), 2);
+        let _: Symbol<String> = table.intern(&"frog".to_owned());
+        let _: Symbol<Address> = table.intern(&Address::from("frog".to_owned()));
+        assert_eq!(table.len(), 2);
+        assert!(!table.is_empty());
+    }
+
+    #[test]
+    fn iter_strings_in_insertion_order() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let _: Symbol<String> = table.intern(&"toad".to_owned());
+        let _: Symbol<String> = table.intern(&"frog".to_owned());
+        let _: Symbol<Address> = table.intern(&Address::from("toad".to_owned()));
+        let _: Symbol<String> = table.intern(&"newt".to_owned());
+        let strings: Vec<String> = table.iter_strings().collect();
+        assert_eq!(strings, vec!["toad", "frog", "newt"]);
+    }
+
+    #[test]
+    fn iter_strings_rev_mirrors() {
+        for flavor in [
+            InternerFlavor::Array,
+            InternerFlavor::Radix,
+            InternerFlavor::Buffer,
+        ] {
+            let mut table = SymbolTable::new(flavor);
+            let _: Vec<Symbol<String>> = table.intern_all(
+                ["toad", "frog", "newt", "axolotl"]
+                    .iter()
+                    .map(|word| word.to_string()),
+            );
+            let mut forward: Vec<String> = table.iter_strings().collect();
+            let backward: Vec<String> = table.iter_strings_rev().collect();
+            forward.reverse();
+            assert_eq!(backward, forward);
+        }
+    }
+
+    #[test]
+    fn intern_all_preserves_order() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let words = ["frog", "toad", "frog", "newt"];
+        let syms: Vec<Symbol<String>> = table.intern_all(words.iter().map(|word| word.to_string()));
+        assert_eq!(syms.len(), 4);
+        assert_eq!(syms[0], syms[2]);
+        assert_ne!(syms[0], syms[1]);
+        for (word, sym) in words.iter().zip(&syms) {
+            assert_eq!(table.resolve(sym), Ok(word.to_string()));
+        }
+        assert_eq!(table.len(), 3);
+    }
+
+    #[test]
+    fn stats_count_entries_and_types() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let _: Symbol<String> = table.intern(&"frog".to_owned());
+        let _: Symbol<String> = table.intern(&"toad".to_owned());
+        let _: Symbol<Address> = table.intern(&Address::from("toad".to_owned()));
+        let stats = table.stats();
+        assert_eq!(stats.num_entries, 2);
+        assert_eq!(stats.total_bytes_stored, 8);
+        assert_eq!(stats.num_type_associations, 3);
+    }
+
+    #[test]
+    fn parse_err_keeps_raw_string() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let sym: Symbol<Lowercase> = table.intern(&Lowercase("TOAD".to_owned()));
+        match table.resolve(&sym) {
+            Err(ResolutionErr::ParseErr { raw }) => assert_eq!(raw, "TOAD"),
+            _ => panic!("Expected a parse error."),
+        }
+    }
+
+    #[test]
+    fn with_resolved_lends_str() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let toad: Symbol<String> = table.intern(&"toad".to_owned());
+        assert_eq!(table.with_resolved(&toad, |val| val.len()), Ok(4));
+        assert_eq!(table.with_resolved(&toad, |val| val == "toad"), Ok(true));
+
+        let other = SymbolTable::new(InternerFlavor::Array);
+        assert!(other.with_resolved(&toad, |val| val.len()).is_err());
+    }
+
+    #[test]
+    fn with_resolved_radix() {
+        let mut table = SymbolTable::new(InternerFlavor::Radix);
+        let _: Symbol<String> = table.intern(&"toast".to_owned());
+        let toad: Symbol<String> = table.intern(&"toad".to_owned());
+        let owned = table.with_resolved(&toad, |val| val.to_owned());
+        assert_eq!(owned, Ok("toad".to_owned()));
+    }
+
+    #[test]
+    fn remove_leaves_tombstone() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let frog: Symbol<String> = table.intern(&"frog".to_owned());
+        let toad: Symbol<String> = table.intern(&"toad".to_owned());
+        let toad_address: Symbol<Address> = table.intern(&Address::from("toad".to_owned()));
+        let newt: Symbol<String> = table.intern(&"newt".to_owned());
+
+        // Removing one of two types keeps the string alive.
+        assert!(table.remove(&toad));
+        assert!(!table.remove(&toad));
+        assert!(!table.has_interned::<String, _>("toad"));
+        assert!(table.has_interned::<Address, _>("toad"));
+        assert_eq!(table.len(), 3);
+
+        // Removing the last type frees it.
+        assert!(table.remove(&toad_address));
+        assert_eq!(table.len(), 2);
+        assert_eq!(table.resolve(&toad), Err(ResolutionErr::UnknownIdErr));
+        assert!(table.resolve(&toad_address).is_err());
+
+        // Other Symbols are unaffected, and the id isn't reused.
+        assert_eq!(table.resolve(&frog), Ok("frog".to_owned()));
+        assert_eq!(table.resolve(&newt), Ok("newt".to_owned()));
+        let again: Symbol<String> = table.intern(&"toad".to_owned());
+        assert_ne!(again, toad);
+        assert_eq!(table.resolve(&again), Ok("toad".to_owned()));
+    }
+
+    #[test]
+    fn remove_rejects_foreign_symbols() {
+        let mut table1 = SymbolTable::new(InternerFlavor::Array);
+        let mut table2 = SymbolTable::new(InternerFlavor::Array);
+        let sym: Symbol<String> = table1.intern(&"frog".to_owned());
+        let _: Symbol<String> = table2.intern(&"frog".to_owned());
+        assert!(!table2.remove(&sym));
+        assert!(table2.has_interned::<String, _>("frog"));
+    }
+
+    #[test]
+    fn clear_empties_table() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let frog: Symbol<String> = table.intern(&"frog".to_owned());
+        let _: Symbol<String> = table.intern(&"toad".to_owned());
+        table.clear();
+        assert_eq!(table.len(), 0);
+        assert!(table.is_empty());
+        assert!(!table.has_interned::<String, _>("frog"));
+        assert_eq!(table.resolve(&frog), Err(ResolutionErr::StaleSymbolErr));
+        let _: Symbol<String> = table.intern(&"newt".to_owned());
+        assert_eq!(table.len(), 1);
+    }
+
+    #[test]
+    fn extend_interns_everything() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let words = vec!["frog".to_owned(), "toad".to_owned(), "frog".to_owned()];
+        table.extend(words);
+        assert!(table.has_interned::<String, _>("frog"));
+        assert!(table.has_interned::<String, _>("toad"));
+        assert_eq!(table.len(), 2);
+    }
+
+    #[test]
+    fn from_iter_with_symbols_binds_to_table() {
+        let words = ["frog", "toad", "frog"].map(String::from);
+        let (table, syms) = SymbolTable::from_iter_with_symbols(words.clone());
+        assert_eq!(table.len(), 2);
+        assert_eq!(syms[0], syms[2]);
+        for (word, sym) in words.iter().zip(&syms) {
+            assert_eq!(table.resolve(sym).as_ref(), Ok(word));
+        }
+
+        let collected: SymbolTable = words.into_iter().collect();
+        assert!(collected.has_interned::<String, _>("toad"));
+        assert_eq!(collected.len(), 2);
+    }
+
+    #[test]
+    fn reserve_keeps_contents() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let frog: Symbol<String> = table.intern(&"frog".to_owned());
+        table.reserve(1000);
+        assert_eq!(table.len(), 1);
+        assert_eq!(table.resolve(&frog), Ok("frog".to_owned()));
+    }
+
+    #[test]
+    fn shrink_keeps_contents() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let syms: Vec<Symbol<String>> = table.intern_all((0..100).map(|i| i.to_string()));
+        for sym in syms.iter().step_by(2) {
+            table.remove(sym);
+        }
+        table.shrink_to_fit();
+        assert_eq!(table.len(), 50);
+        for (i, sym) in syms.iter().enumerate().skip(1).step_by(2) {
+            assert_eq!(table.resolve(sym), Ok(i.to_string()));
+        }
+    }
+
+    #[test]
+    fn merge_copies_every_string() {
+        let mut left = SymbolTable::new(InternerFlavor::Array);
+        let mut right = SymbolTable::new(InternerFlavor::Radix);
+        let frog: Symbol<String> = left.intern(&"frog".to_owned());
+        right.intern(&"zebra".to_owned());
+        let toad: Symbol<String> = right.intern(&"toad".to_owned());
+        let bob = right.intern(&Username("bob".to_owned()));
+        let remap = left.merge(&right);
+        assert_eq!(remap.len(), 3);
+        assert!(left.has_interned::<String, _>("zebra"));
+        assert!(left.has_interned::<String, _>("toad"));
+        assert!(left.has_interned::<Username, _>("bob"));
+        assert!(!left.has_interned::<String, _>("bob"));
+        assert_eq!(left.len(), 4);
+        // Translated Symbols resolve against the merged table.
+        let new_toad = remap.translate(toad.clone());
+        assert_eq!(left.resolve(&new_toad), Ok("toad".to_owned()));
+        assert_eq!(left.get_interned::<String, _>("toad"), Some(new_toad));
+        let new_bob = remap.translate(bob);
+        assert_eq!(
+            left.resolve(&new_bob).ok().map(|bob| bob.0),
+            Some("bob".to_owned())
+        );
+        // Symbols from elsewhere can't be translated.
+        assert!(remap.try_translate(&frog).is_none());
+        assert_eq!(right.resolve(&toad), Ok("toad".to_owned()));
+    }
+
+    #[test]
+    fn merge_with_self() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let toad: Symbol<String> = table.intern(&"toad".to_owned());
+        let other = table.clone();
+        let remap = table.merge(&other);
+        assert_eq!(table.len(), 1);
+        assert_eq!(remap.translate(toad.clone()), toad);
+    }
+
+    #[test]
+    fn compact_renumbers_densely() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let syms: Vec<Symbol<String>> =
+            table.intern_all(["toad", "frog", "newt", "axolotl"].map(String::from));
+        let olm = table.intern(&Address::from("olm".to_owned()));
+        table.remove(&syms[0]);
+        table.remove(&syms[2]);
+        let remap = table.compact();
+        assert_eq!(table.len(), 3);
+        assert!(syms[1].is_stale());
+        let frog = remap.translate(syms[1].clone());
+        let axolotl = remap.translate(syms[3].clone());
+        let olm = remap.translate(olm);
+        assert_eq!(frog.raw(), 1);
+        assert_eq!(axolotl.raw(), 2);
+        assert_eq!(olm.raw(), 3);
+        assert_eq!(table.resolve(&frog), Ok("frog".to_owned()));
+        assert_eq!(table.resolve(&axolotl), Ok("axolotl".to_owned()));
+        assert_eq!(olm.to_string(), "olm");
+        // • Removed strings and fresh Symbols can't be translated.
+        assert!(remap.try_translate(&syms[0]).is_none());
+        assert!(remap.try_translate(&frog).is_none());
+    }
+
+    #[test]
+    fn sort_symbols_alphabetically() {
+        let mut table = SymbolTable::new(InternerFlavor::Radix);
+        let mut syms: Vec<Symbol<String>> =
+            table.intern_all(["toad", "frog", "newt", "axolotl"].map(String::from));
+        table.sort_symbols(&mut syms);
+        let sorted: Vec<String> = syms.iter().map(|sym| sym.to_string()).collect();
+        assert_eq!(sorted, ["axolotl", "frog", "newt", "toad"]);
+    }
+
+    #[test]
+    fn entries_by_type() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let home = table.intern(&Address("1 Main St".to_owned()));
+        let bob = table.intern(&Username("bob".to_owned()));
+        let shared = table.intern(&Address("bob".to_owned()));
+        let addresses: Vec<(Symbol<Address>, String)> = table.entries().collect();
+        assert_eq!(addresses.len(), 2);
+        assert!(addresses[0].0 == home && addresses[0].1 == "1 Main St");
+        assert!(addresses[1].0 == shared && addresses[1].1 == "bob");
+        let usernames: Vec<(Symbol<Username>, String)> = table.entries().collect();
+        assert_eq!(usernames.len(), 1);
+        assert!(usernames[0].0 == bob && usernames[0].1 == "bob");
+        assert_eq!(table.entries::<Lowercase>().count(), 0);
+    }
+
+    // A minimal third-party interner, which ignores types entirely. If it
+    // has a limit, it runs out of ids once it holds that many strings. The
+    // string at values[i] is stored under the id `first_id + i`.
+    struct ListInterner {
+        values:   Vec<String>,
+        limit:    Option<usize>,
+        first_id: u64,
+    }
+
+    impl Default for ListInterner {
+        fn default() -> Self {
+            Self {
+                values:   Vec::new(),
+                limit:    None,
+                first_id: 1,
+            }
+        }
+    }
+
+    impl ListInterner {
+        fn id(&self, position: usize) -> SerialU64<()> {
+            SerialU64::try_from(self.first_id + position as u64).unwrap()
+        }
+    }
+
+    impl Interner for ListInterner {
+        fn intern(&mut self, val: String, _typ: TypeId) -> Result<SerialU64<()>, InternErr> {
+            let position = match self.values.iter().position(|x| *x == val) {
+                Some(position) => position,
+                None if Some(self.values.len()) == self.limit => {
+                    return Err(InternErr::IdSpaceExhausted);
+                }
+                None => {
+                    self.values.push(val);
+                    self.values.len() - 1
+                }
+            };
+            Ok(self.id(position))
+        }
+
+        fn resolve(&self, id: SerialU64<()>) -> Option<String> {
+            let position = id.get().checked_sub(self.first_id)?;
+            self.values.get(position as usize).cloned()
+        }
+
+        fn get_interned(&self, val: String, _typ: TypeId) -> Option<SerialU64<()>> {
+            let position = self.values.iter().position(|x| *x == val)?;
+            Some(self.id(position))
+        }
+
+        fn entries(&self) -> Vec<(SerialU64<()>, String, Vec<TypeId>)> {
+            let typ = TypeId::of::<String>();
+            (0..self.values.len())
+                .map(|position| (self.id(position), self.values[position].clone(), vec![typ]))
+                .collect()
+        }
+
+        fn remove(&mut self, _id: SerialU64<()>, _typ: TypeId) -> bool {
+            false
+        }
+
+        fn clear(&mut self) {
+            self.values.clear();
+        }
+
+        fn len(&self) -> usize {
+            self.values.len()
+        }
+
+        fn stats(&self) -> TableStats {
+            TableStats::default()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    #[test]
+    fn custom_interner() {
+        let mut table = SymbolTable::with_interner(ListInterner::default());
+        let toad: Symbol<String> = table.intern(&"toad".to_owned());
+        let frog: Symbol<String> = table.intern(&"frog".to_owned());
+        assert_eq!(toad.id().get(), 1);
+        assert_eq!(frog.id().get(), 2);
+        assert!(!toad.is_null());
+        assert_eq!(table.resolve(&frog), Ok("frog".to_owned()));
+        assert_eq!(table.get_interned::<String, _>("toad"), Some(toad));
+        assert_eq!(table.len(), 2);
+    }
+
+    #[test]
+    fn try_intern_surfaces_exhaustion() {
+        let mut table = SymbolTable::with_interner(ListInterner {
+            limit: Some(2),
+            ..ListInterner::default()
+        });
+        let toad: Symbol<String> = table.try_intern(&"toad".to_owned()).unwrap();
+        let _: Symbol<String> = table.try_intern(&"frog".to_owned()).unwrap();
+        let newt: Result<Symbol<String>, InternErr> = table.try_intern(&"newt".to_owned());
+        assert_eq!(newt.map(|sym| sym.raw()), Err(InternErr::IdSpaceExhausted));
+        assert_eq!(table.len(), 2);
+        // • Strings which are already stored can still be interned.
+        assert_eq!(table.try_intern(&"toad".to_owned()), Ok(toad));
+    }
+
+    #[test]
+    #[should_panic(expected = "failed to intern")]
+    fn intern_panics_on_exhaustion() {
+        let mut table = SymbolTable::with_interner(ListInterner {
+            limit: Some(0),
+            ..ListInterner::default()
+        });
+        let _: Symbol<String> = table.intern(&"toad".to_owned());
+    }
+
+    #[test]
+    fn concat_joins_symbols() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let foo: Symbol<String> = table.intern(&"foo".to_owned());
+        let bar: Symbol<String> = table.intern(&"bar".to_owned());
+        let joined = table.concat(&foo, &bar, ".").unwrap();
+        assert_eq!(table.resolve(&joined), Ok("foo.bar".to_owned()));
+        assert_eq!(table.get_interned::<String, _>("foo.bar"), Some(joined));
+        let mut other = SymbolTable::new(InternerFlavor::Array);
+        let baz: Symbol<String> = other.intern(&"baz".to_owned());
+        assert!(matches!(
+            table.concat(&foo, &baz, "."),
+            Err(ResolutionErr::MismatchErr(_))
+        ));
+        assert_eq!(table.len(), 3);
+    }
+
+    #[test]
+    fn owns_only_its_symbols() {
+        let mut left = SymbolTable::new(InternerFlavor::Array);
+        let mut right = SymbolTable::new(InternerFlavor::Array);
+        let toad: Symbol<String> = left.intern(&"toad".to_owned());
+        let frog: Symbol<String> = right.intern(&"toad".to_owned());
+        assert!(left.owns(&toad));
+        assert!(!left.owns(&frog));
+        assert!(right.owns(&frog));
+        assert!(!right.owns(&toad));
+        assert!(left.clone().owns(&toad));
+    }
+
+    #[test]
+    fn stale_symbols_are_disowned() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let toad: Symbol<String> = table.intern(&"toad".to_owned());
+        table.clear();
+        let frog: Symbol<String> = table.intern(&"frog".to_owned());
+        assert_eq!(toad.raw(), frog.raw());
+        assert!(!table.owns(&toad));
+        assert!(table.owns(&frog));
+        assert!(!table.same_content(&toad, &frog));
+        assert!(table.same_content(&frog, &frog));
+    }
+
+    #[test]
+    fn types_for_lists_every_type() {
+        for flavor in [
+            InternerFlavor::Array,
+            InternerFlavor::Radix,
+            InternerFlavor::Suffix,
+        ] {
+            let mut table = SymbolTable::new(flavor);
+            table.intern(&Address("bob".to_owned()));
+            table.intern(&Username("bob".to_owned()));
+            table.intern(&Username("bobby".to_owned()));
+            let mut typs = table.types_for("bob");
+            typs.sort();
+            let mut expected = vec![TypeId::of::<Address>(), TypeId::of::<Username>()];
+            expected.sort();
+            assert_eq!(typs, expected);
+            assert_eq!(table.types_for("bobby"), [TypeId::of::<Username>()]);
+            assert!(table.types_for("bo").is_empty());
+            assert!(table.types_for("alice").is_empty());
+        }
+    }
+
+    #[test]
+    fn intern_str_matches_intern() {
+        for flavor in [InternerFlavor::Array, InternerFlavor::Radix] {
+            let mut table = SymbolTable::new(flavor);
+            let toad: Symbol<String> = table.intern(&"toad".to_owned());
+            assert_eq!(table.intern_str::<String>("toad"), toad);
+            let frog: Symbol<String> = table.intern_str("frog");
+            assert_eq!(table.resolve(&frog), Ok("frog".to_owned()));
+            assert_eq!(table.intern(&"frog".to_owned()), frog);
+            // The same string as another type shares the entry.
+            let bob: Symbol<Username> = table.intern_str("toad");
+            assert_eq!(bob.id().get(), toad.id().get());
+            assert_eq!(table.len(), 2);
+        }
+    }
+
+    #[test]
+    fn intern_reports_status() {
+        for flavor in [InternerFlavor::Array, InternerFlavor::Radix] {
+            let mut table = SymbolTable::new(flavor);
+            let (toad, is_new) = table.intern_with_status(&"toad".to_owned());
+            assert!(is_new);
+            let (again, is_new) = table.intern_with_status(&"toad".to_owned());
+            assert!(!is_new);
+            assert_eq!(toad, again);
+            let (_, is_new) = table.intern_with_status(&Username("toad".to_owned()));
+            assert!(!is_new);
+        }
+    }
+
+    #[test]
+    fn resolve_many_keeps_order() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let mut other = SymbolTable::new(InternerFlavor::Array);
+        let toad: Symbol<String> = table.intern(&"toad".to_owned());
+        let foreign: Symbol<String> = other.intern(&"newt".to_owned());
+        let frog: Symbol<String> = table.intern(&"frog".to_owned());
+        let results = table.resolve_many(&[frog, foreign, toad]);
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0], Ok("frog".to_owned()));
+        assert!(matches!(results[1], Err(ResolutionErr::MismatchErr(_))));
+        assert_eq!(results[2], Ok("toad".to_owned()));
+        assert!(table.resolve_many::<String>(&[]).is_empty());
+    }
+
+    #[test]
+    fn empty_string_round_trips() {
+        for flavor in [
+            InternerFlavor::Array,
+            InternerFlavor::Radix,
+            InternerFlavor::Suffix,
+        ] {
+            let mut table = SymbolTable::new(flavor);
+            let toad: Symbol<String> = table.intern(&"toad".to_owned());
+            let empty: Symbol<String> = table.intern(&String::new());
+            assert_ne!(empty, toad);
+            assert_ne!(empty.raw(), 0);
+            assert_eq!(table.resolve(&empty), Ok(String::new()));
+            assert_eq!(table.intern(&String::new()), empty);
+            assert_eq!(table.get_interned::<String, _>(""), Some(empty));
+            assert_eq!(table.symbol_from_raw::<String>(0), None);
+            assert_eq!(table.len(), 2);
+        }
+    }
+
+    #[test]
+    fn content_equality() {
+        let mut left = SymbolTable::new(InternerFlavor::Array);
+        let mut right = SymbolTable::new(InternerFlavor::Radix);
+        left.intern(&"toad".to_owned());
+        left.intern(&Username("frog".to_owned()));
+        right.intern(&Username("frog".to_owned()));
+        assert!(!left.content_eq(&right));
+        assert!(!right.content_eq(&left));
+        right.intern(&"toad".to_owned());
+        assert!(left.content_eq(&right));
+        assert!(right.content_eq(&left));
+        assert!(left.content_eq(&left));
+        // The same string under different types isn't equal.
+        right.intern(&"frog".to_owned());
+        assert!(!left.content_eq(&right));
+        left.intern(&"frog".to_owned());
+        assert!(left.content_eq(&right));
+    }
+
+    #[test]
+    fn map_symbol_reinterns() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let toad: Symbol<String> = table.intern(&"toad".to_owned());
+        let shout = table.map_symbol(&toad, str::to_uppercase).unwrap();
+        assert_ne!(shout, toad);
+        assert_eq!(table.resolve(&shout), Ok("TOAD".to_owned()));
+        assert_eq!(table.resolve(&toad), Ok("toad".to_owned()));
+        // Mapping to an existing string returns its Symbol.
+        let same = table.map_symbol(&shout, str::to_lowercase).unwrap();
+        assert_eq!(same, toad);
+        let mut other = SymbolTable::new(InternerFlavor::Array);
+        assert!(matches!(
+            other.map_symbol(&toad, str::to_uppercase),
+            Err(ResolutionErr::MismatchErr(_))
+        ));
+    }
+
+    #[test]
+    fn intern_chars_collects() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let frog: Symbol<String> = table.intern_chars("frog".chars());
+        assert_eq!(table.resolve(&frog), Ok("frog".to_owned()));
+        assert_eq!(table.intern(&"frog".to_owned()), frog);
+        let backwards: Symbol<String> = table.intern_chars("gorf".chars().rev());
+        assert_eq!(backwards, frog);
+        let empty: Symbol<String> = table.intern_chars(std::iter::empty());
+        assert_eq!(table.resolve(&empty), Ok(String::new()));
+        assert_ne!(empty, frog);
+    }
+
+    #[test]
+    fn downcast_interner() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        table.intern(&"toad".to_owned());
+        let array = table.interner_ref::<ArrayInterner>();
+        assert_eq!(array.map(|array| array.len()), Some(1));
+        assert!(table.interner_ref::<RadixInterner>().is_none());
+        let custom = SymbolTable::with_interner(ListInterner::default());
+        assert!(custom.interner_ref::<ListInterner>().is_some());
+        assert!(custom.interner_ref::<ArrayInterner>().is_none());
+    }
+
+    #[test]
+    fn symbols_by_type() {
+        let mut table = SymbolTable::new(InternerFlavor::Radix);
+        let toad: Symbol<String> = table.intern(&"toad".to_owned());
+        table.intern(&Username("bob".to_owned()));
+        let frog: Symbol<String> = table.intern(&"frog".to_owned());
+        let syms: Vec<Symbol<String>> = table.symbols().collect();
+        assert_eq!(syms, [toad, frog]);
+        let resolved: Vec<String> = syms.iter().map(|sym| table.resolve(sym).unwrap()).collect();
+        assert_eq!(resolved, ["toad", "frog"]);
+        assert_eq!(table.symbols::<Username>().count(), 1);
+        assert_eq!(table.symbols::<Address>().count(), 0);
+    }
+
+    #[test]
+    fn intern_path_prefixes() {
+        let mut table = SymbolTable::new(InternerFlavor::Radix);
+        let levels: Vec<Symbol<String>> = table.intern_path("a/b/c", '/');
+        let resolved: Vec<String> = levels.iter().map(|sym| sym.to_string()).collect();
+        assert_eq!(resolved, ["a", "a/b", "a/b/c"]);
+        for prefix in ["a", "a/b", "a/b/c"] {
+            assert!(table.has_interned::<String, _>(prefix));
+        }
+        let rooted: Vec<Symbol<String>> = table.intern_path("/usr/lib", '/');
+        let resolved: Vec<String> = rooted.iter().map(|sym| sym.to_string()).collect();
+        assert_eq!(resolved, ["/usr", "/usr/lib"]);
+        assert!(!table.has_interned::<String, _>(""));
+        // Shared parents are only stored once.
+        let sibling: Vec<Symbol<String>> = table.intern_path("a/b/d", '/');
+        assert_eq!(sibling[1], levels[1]);
+        assert_eq!(table.len(), 6);
+    }
+
+    #[test]
+    fn intern_as_shares_an_id() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let (home, bob): (Symbol<Address>, Symbol<Username>) = table.intern_as("bob");
+        let id = home.raw();
+        assert_eq!(bob.raw(), id);
+        assert_eq!(table.len(), 1);
+        assert_eq!(table.get_interned::<Address, _>("bob"), Some(home));
+        assert_eq!(table.get_interned::<Username, _>("bob"), Some(bob));
+        assert!(!table.has_interned::<String, _>("bob"));
+        let (name, again, text) = crate::intern_as!(table, "bob", Address, Username, String);
+        assert_eq!(again.raw(), id);
+        assert_eq!(name.raw(), text.raw());
+        assert_eq!(table.types_for("bob").len(), 3);
+    }
+
+    #[test]
+    fn out_of_range_raw_is_rejected() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let _: Symbol<String> = table.intern(&"frog".to_owned());
+        assert!(table.symbol_from_raw::<String>(u64::MAX).is_none());
+        assert!(table.symbol_from_raw::<String>(u64::MAX - 1).is_none());
+        assert!(table.symbol_from_raw::<String>(1).is_some());
+        // • Every id an interner can hold converts to a typed id, so the
+        //   conversion's error branch is only reachable from a raw id.
+        assert!(matches!(
+            table.upcast::<String>(u64::MAX),
+            Err(ResolutionErr::UnknownIdErr)
+        ));
+        assert_eq!(table.upcast::<String>(1).map(|id| id.get()).ok(), Some(1));
+    }
+
+    #[test]
+    fn estimated_heap_bytes_grow() {
+        for flavor in [
+            InternerFlavor::Array,
+            InternerFlavor::Radix,
+            InternerFlavor::Suffix,
+            InternerFlavor::Bounded(8),
+            InternerFlavor::Buffer,
+        ] {
+            let mut table = SymbolTable::new(flavor);
+            let before = table.stats().estimated_heap_bytes;
+            for word in ["frog", "toad", "newt", "salamander"] {
+                let _: Symbol<String> = table.intern(&word.to_owned());
+            }
+            assert!(table.stats().estimated_heap_bytes > before);
+        }
+    }
+
+    #[test]
+    fn drain_empties_table() {
+        for flavor in [
+            InternerFlavor::Array,
+            InternerFlavor::Radix,
+            InternerFlavor::Buffer,
+        ] {
+            let mut table = SymbolTable::new(flavor);
+            let frog: Symbol<String> = table.intern(&"frog".to_owned());
+            let _: Symbol<String> = table.intern(&"toad".to_owned());
+            let _: Symbol<Address> = table.intern(&Address::from("toad".to_owned()));
+            let _: Symbol<String> = table.intern(&"newt".to_owned());
+            let drained: BTreeSet<String> = table.drain().collect();
+            let expected: BTreeSet<String> = ["frog", "toad", "newt"]
+                .iter()
+                .map(|word| word.to_string())
+                .collect();
+            assert_eq!(drained, expected);
+            assert_eq!(table.len(), 0);
+            assert_eq!(table.resolve(&frog), Err(ResolutionErr::StaleSymbolErr));
+            assert_eq!(table.drain().count(), 0);
+        }
+    }
+
+    #[test]
+    fn iter_sorted_is_alphabetical() {
+        for flavor in [
+            InternerFlavor::Array,
+            InternerFlavor::Radix,
+            InternerFlavor::Suffix,
+        ] {
+            let mut table = SymbolTable::new(flavor);
+            for word in ["toad", "frog", "newt", "axolotl", "toa", "Zebra"] {
+                let _: Symbol<String> = table.intern(&word.to_owned());
+            }
+            let sorted: Vec<String> = table.iter_sorted().collect();
+            assert_eq!(sorted, ["Zebra", "axolotl", "frog", "newt", "toa", "toad"]);
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_intern_all_matches_intern_all() {
+        let words: Vec<String> = (0..2000)
+            .map(|n| format!("word{}", (n * 7919) % 313))
+            .collect();
+        let mut serial = SymbolTable::new(InternerFlavor::Array);
+        let _: Symbol<String> = serial.intern(&"word5".to_owned());
+        let expected: Vec<u64> = serial
+            .intern_all(words.clone())
+            .iter()
+            .map(|sym| sym.raw())
+            .collect();
+        let mut parallel = SymbolTable::new(InternerFlavor::Array);
+        let _: Symbol<String> = parallel.intern(&"word5".to_owned());
+        let syms = parallel.par_intern_all(words.clone());
+        let actual: Vec<u64> = syms.iter().map(|sym| sym.raw()).collect();
+        assert_eq!(actual, expected);
+        assert_eq!(parallel.len(), serial.len());
+        for (word, sym) in words.iter().zip(&syms) {
+            assert_eq!(parallel.resolve(sym).as_ref(), Ok(word));
+        }
+        assert!(parallel.par_intern_all(Vec::<String>::new()).is_empty());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_intern_all_matches_intern_all_when_evicting() {
+        // • Repeats of toad keep it fresh while the others are evicted
+        //   and come back under new ids.
+        let words: Vec<String> = [
+            "toad", "frog", "newt", "toad", "olm", "frog", "toad", "newt",
+        ]
+        .iter()
+        .map(|word| word.to_string())
+        .collect();
+        let bounded = || SymbolTable::new(InternerFlavor::Bounded(2));
+        let wrapped =
+            || SymbolTable::from(CaseInsensitiveInterner::new(InternerFlavor::Bounded(2)));
+        for new in [&bounded as &dyn Fn() -> SymbolTable, &wrapped] {
+            let mut serial = new();
+            let expected: Vec<u64> = serial
+                .intern_all(words.clone())
+                .iter()
+                .map(|sym| sym.raw())
+                .collect();
+            let mut parallel = new();
+            let syms = parallel.par_intern_all(words.clone());
+            let actual: Vec<u64> = syms.iter().map(|sym| sym.raw()).collect();
+            assert_eq!(actual, expected);
+            let survivors: Vec<String> = parallel.iter_sorted().collect();
+            assert_eq!(survivors, serial.iter_sorted().collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn same_content_across_types() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let home: Symbol<Address> = table.intern(&Address::from("bob".to_owned()));
+        let bob: Symbol<Username> = table.intern(&Username::from("bob".to_owned()));
+        let alice: Symbol<Username> = table.intern(&Username::from("alice".to_owned()));
+        assert!(table.same_content(&home, &bob));
+        assert!(table.same_content(&bob, &home));
+        assert!(!table.same_content(&home, &alice));
+        let mut other = SymbolTable::new(InternerFlavor::Array);
+        let foreign: Symbol<Username> = other.intern(&Username::from("bob".to_owned()));
+        assert_eq!(foreign.raw(), bob.raw());
+        assert!(!table.same_content(&home, &foreign));
+    }
+
+    #[test]
+    fn clear_type_keeps_other_types() {
+        for flavor in [
+            InternerFlavor::Array,
+            InternerFlavor::Radix,
+            InternerFlavor::FxHash,
+        ] {
+            let mut table = SymbolTable::new(flavor);
+            let home: Symbol<Address> = table.intern(&Address::from("bob".to_owned()));
+            let _: Symbol<Address> = table.intern(&Address::from("elm st".to_owned()));
+            let bob: Symbol<Username> = table.intern(&Username::from("bob".to_owned()));
+            table.clear_type::<Address>();
+            assert!(!table.has_interned::<Address, _>("bob"));
+            assert!(!table.has_interned::<Address, _>("elm st"));
+            assert!(table.has_interned::<Username, _>("bob"));
+            assert!(table.resolve(&bob).is_ok());
+            assert_eq!(
+                table.resolve(&home).ok().map(|home| home.0),
+                Some("bob".to_owned())
+            );
+            assert_eq!(table.len(), 1);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn bytes_round_trip() {
+        let mut table = SymbolTable::new(InternerFlavor::Radix);
+        let frog: Symbol<String> = table.intern(&"frog".to_owned());
+        let newt: Symbol<String> = table.intern(&"newt".to_owned());
+        let _: Symbol<Address> = table.intern(&Address::from("frog".to_owned()));
+        let _: Symbol<String> = table.intern(&"café".to_owned());
+        table.remove(&newt);
+        let bytes = table.to_bytes();
+        let loaded = SymbolTable::from_bytes(&bytes).unwrap();
+        assert!(loaded.content_eq(&table));
+        let reloaded: Symbol<String> = loaded.get_interned("frog").unwrap();
+        assert_eq!(reloaded.id(), frog.id());
+        assert!(loaded.resolve(&frog).is_err());
+        let empty = SymbolTable::new(InternerFlavor::Array);
+        assert!(SymbolTable::from_bytes(&empty.to_bytes())
+            .unwrap()
+            .is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn bad_bytes_fail_to_decode() {
+        use crate::DecodeErr;
+
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let _: Symbol<String> = table.intern(&"frog".to_owned());
+        let bytes = table.to_bytes();
+        let truncated = SymbolTable::from_bytes(&bytes[..bytes.len() - 1]);
+        assert_eq!(truncated.err(), Some(DecodeErr::Truncated));
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert_eq!(
+            SymbolTable::from_bytes(&trailing).err(),
+            Some(DecodeErr::TrailingBytes)
+        );
+        let mut sentinel = bytes.clone();
+        sentinel[16..24].copy_from_slice(&0u64.to_le_bytes());
+        assert_eq!(
+            SymbolTable::from_bytes(&sentinel).err(),
+            Some(DecodeErr::OutOfOrderId(0))
+        );
+        let mut garbled = bytes.clone();
+        garbled[32] = 0xff;
+        assert_eq!(
+            SymbolTable::from_bytes(&garbled).err(),
+            Some(DecodeErr::InvalidUtf8)
+        );
+        // • An id past the declared bound would otherwise allocate a slot
+        //   for every id below it.
+        let mut huge = bytes.clone();
+        huge[16..24].copy_from_slice(&(1u64 << 40).to_le_bytes());
+        assert_eq!(
+            SymbolTable::from_bytes(&huge).err(),
+            Some(DecodeErr::InvalidId(1 << 40))
+        );
+        let mut untyped = bytes;
+        untyped.truncate(untyped.len() - 16);
+        untyped.extend_from_slice(&0u64.to_le_bytes());
+        assert_eq!(
+            SymbolTable::from_bytes(&untyped).err(),
+            Some(DecodeErr::UntypedEntry(1))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn repeated_strings_fail_to_decode() {
+        use crate::DecodeErr;
+
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let frog: Symbol<String> = table.intern(&"frog".to_owned());
+        let _: Symbol<String> = table.intern(&"toad".to_owned());
+        let _: Symbol<String> = table.intern(&"newt".to_owned());
+        table.remove(&frog);
+        let mut bytes = table.to_bytes();
+        // • The header declares one removed id below the last entry.
+        assert_eq!(bytes[8..16], 1u64.to_le_bytes());
+        let toad = bytes.windows(4).position(|w| w == b"toad").unwrap();
+        bytes[toad..toad + 4].copy_from_slice(b"newt");
+        assert_eq!(
+            SymbolTable::from_bytes(&bytes).err(),
+            Some(DecodeErr::DuplicateString(3))
+        );
+    }
+
+    #[test]
+    fn case_transforms() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let loud: Symbol<String> = table.intern(&"TOAD".to_owned());
+        let quiet = table.to_lowercase(&loud).unwrap();
+        assert_eq!(table.resolve(&quiet), Ok("toad".to_owned()));
+        assert_eq!(table.resolve(&loud), Ok("TOAD".to_owned()));
+        assert_eq!(table.to_uppercase(&quiet), Ok(loud));
+        let street: Symbol<String> = table.intern(&"straße".to_owned());
+        let shouted = table.to_uppercase(&street).unwrap();
+        assert_eq!(table.resolve(&shouted), Ok("STRASSE".to_owned()));
+        let mut other = SymbolTable::new(InternerFlavor::Array);
+        assert!(other.to_lowercase(&street).is_err());
+    }
+
+    #[test]
+    fn replace_substrings() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let toad: Symbol<String> = table.intern(&"toad".to_owned());
+        let teed = table.replace(&toad, "oa", "ee").unwrap();
+        assert_eq!(table.resolve(&teed), Ok("teed".to_owned()));
+        assert_eq!(table.resolve(&toad), Ok("toad".to_owned()));
+        // • Without a match, the original Symbol comes back.
+        assert_eq!(table.replace(&toad, "frog", "newt"), Ok(toad));
+    }
+
+    #[test]
+    fn cleared_symbols_are_stale() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let frog: Symbol<String> = table.intern(&"frog".to_owned());
+        assert!(!frog.is_stale());
+        table.clear();
+        // • The new string takes over frog's id, but frog can't resolve to it.
+        let newt: Symbol<String> = table.intern(&"newt".to_owned());
+        assert_eq!(newt.raw(), frog.raw());
+        assert!(frog.is_stale());
+        assert_ne!(frog, newt);
+        assert_eq!(table.resolve(&frog), Err(ResolutionErr::StaleSymbolErr));
+        assert_eq!(frog.resolve(), Err(ResolutionErr::StaleSymbolErr));
+        assert!(!table.remove(&frog));
+        assert_eq!(table.resolve(&newt), Ok("newt".to_owned()));
+        assert_eq!(
+            frog.to_string(),
+            format!("<unresolvable symbol #{}>", frog.raw())
+        );
+        // • Draining advances the generation too.
+        table.drain().for_each(drop);
+        assert!(newt.is_stale());
+    }
+
+    #[test]
+    fn interned_under_any_type() {
+        for flavor in [
+            InternerFlavor::Array,
+            InternerFlavor::Radix,
+            InternerFlavor::Suffix,
+            InternerFlavor::Bounded(4),
+            InternerFlavor::Buffer,
+            InternerFlavor::FxHash,
+            InternerFlavor::Sorted,
+        ] {
+            let mut table = SymbolTable::new(flavor);
+            let home: Symbol<Address> = table.intern(&Address::from("bob".to_owned()));
+            assert!(!table.has_interned::<Username, _>("bob"));
+            assert!(table.has_interned_any("bob"));
+            assert_eq!(table.get_interned_any("bob"), Some(home.erase_type()));
+            assert!(!table.has_interned_any("alice"));
+            table.remove(&home);
+            assert!(!table.has_interned_any("bob"));
+        }
+        let mut table = SymbolTable::from(CaseInsensitiveInterner::new(InternerFlavor::Array));
+        let home: Symbol<Address> = table.intern(&Address::from("Bob".to_owned()));
+        assert_eq!(table.get_interned_any("BOB"), Some(home.erase_type()));
+        table.remove(&home);
+        assert!(!table.has_interned_any("bob"));
+    }
+
+    #[test]
+    fn intern_reserve_matches_intern() {
+        let flavors = [
+            (InternerFlavor::Array, InternerFlavor::Array),
+            (InternerFlavor::Buffer, InternerFlavor::Buffer),
+        ];
+        for (plain_flavor, reserved_flavor) in flavors {
+            let mut plain = SymbolTable::new(plain_flavor);
+            let mut reserved = SymbolTable::new(reserved_flavor);
+            for word in ["frog", "toad", "frog", "salamander"] {
+                let expected: Symbol<String> = plain.intern(&word.to_owned());
+                let actual: Symbol<String> = reserved.intern_reserve(&word.to_owned(), 1024);
+                assert_eq!(actual.raw(), expected.raw());
+                assert_eq!(reserved.resolve(&actual), Ok(word.to_owned()));
+            }
+            assert!(reserved.content_eq(&plain));
+        }
+    }
+
+    #[test]
+    fn trimmed_and_normalized() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let toad: Symbol<String> = table.intern_str("toad");
+        let padded: Symbol<String> = table.intern_trimmed(" toad\t");
+        assert_eq!(padded, toad);
+        let spotted: Symbol<String> = table.intern_str("spotted toad");
+        let spaced: Symbol<String> = table.intern_normalized("\n spotted \t toad ");
+        assert_eq!(spaced, spotted);
+        assert_eq!(table.len(), 2);
+    }
+
+    #[test]
+    fn symbols_after_checkpoint() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let _: Vec<Symbol<String>> = table.intern_all(["toad", "frog"].map(String::from));
+        let checkpoint: Symbol<String> = table.intern(&"newt".to_owned());
+        let _: Vec<Symbol<String>> = table.intern_all(["axolotl", "toad", "olm"].map(String::from));
+        let _: Symbol<Address> = table.intern(&Address::from("salamander".to_owned()));
+        let recent: Vec<Symbol<String>> = table.symbols_in_range(checkpoint.raw() + 1..u64::MAX);
+        let recent: Vec<String> = recent.iter().map(|sym| sym.to_string()).collect();
+        assert_eq!(recent, vec!["axolotl", "olm"]);
+        assert!(table.symbols_in_range::<String>(0..1).is_empty());
+    }
+
+    #[test]
+    fn prefix_search() {
+        for flavor in [InternerFlavor::Array, InternerFlavor::Radix] {
+            let mut table = SymbolTable::new(flavor);
+            let syms: Vec<Symbol<String>> =
+                table.intern_all(["toad", "toast", "frog"].map(String::from));
+            let _: Symbol<Address> = table.intern(&Address::from("tomato".to_owned()));
+            let found: Vec<u64> = table
+                .with_prefix::<String>("to")
+                .iter()
+                .map(Symbol::raw)
+                .collect();
+            assert_eq!(found, vec![syms[0].raw(), syms[1].raw()]);
+            assert_eq!(table.with_prefix::<String>("").len(), 3);
+            assert!(table.with_prefix::<String>("newt").is_empty());
+        }
+    }
+
+    #[test]
+    fn counts_hits_and_misses() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let _: Symbol<String> = table.intern(&"toad".to_owned());
+        assert_eq!((table.intern_hits(), table.intern_misses()), (0, 1));
+        let _: Symbol<String> = table.intern(&"toad".to_owned());
+        assert_eq!((table.intern_hits(), table.intern_misses()), (1, 1));
+        // • A string stored under another type still counts as a hit.
+        let _: Symbol<Address> = table.intern(&Address::from("toad".to_owned()));
+        let _: Symbol<String> = table.intern_str("frog");
+        let _: Symbol<String> = table.intern_str("frog");
+        let _: Vec<Symbol<String>> = table.intern_all(["newt", "frog"].map(String::from));
+        assert_eq!((table.intern_hits(), table.intern_misses()), (4, 3));
+        let clone = table.clone();
+        assert_eq!(clone.intern_hits(), 4);
+    }
+
+    #[test]
+    fn token_round_trip() {
+        let mut left = SymbolTable::new(InternerFlavor::Array);
+        let _: Symbol<String> = left.intern(&"frog".to_owned());
+        let url: Symbol<String> = left.intern(&"http://toad".to_owned());
+        let token = url.to_token().unwrap();
+        assert_eq!(token, "2:http://toad");
+        let mut right = SymbolTable::new(InternerFlavor::Radix);
+        let copy: Symbol<String> = right.from_token(&token).unwrap();
+        assert_eq!(copy.raw(), 1);
+        assert_eq!(right.resolve(&copy), Ok("http://toad".to_owned()));
+        assert_eq!(left.from_token(&token), Some(url));
+        assert!(right.from_token::<String>("toad").is_none());
+        assert!(right.from_token::<String>("one:toad").is_none());
+    }
+
+    #[test]
+    fn empty_tokens_need_an_interned_empty_string() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let frog: Symbol<String> = table.intern_str("frog");
+        table.clear();
+        assert_eq!(frog.to_token(), None);
+        assert!(table.from_token::<String>("1:").is_none());
+        assert!(table.is_empty());
+        let empty: Symbol<String> = table.intern_str("");
+        assert_eq!(empty.to_token(), Some("1:".to_owned()));
+        assert_eq!(table.from_token("1:"), Some(empty));
+    }
+
+    #[test]
+    fn get_or_intern_is_idempotent() {
+        let mut table = SymbolTable::new(InternerFlavor::Array);
+        let toad: Symbol<String> = table.get_or_intern("toad");
+        assert_eq!(table.get_or_intern("toad"), toad);
+        assert_eq!(table.get_interned("toad"), Some(toad));
+        assert_eq!(table.len(), 1);
+        let frog: Symbol<Lowercase> = table.get_or_intern_default("frog");
+        assert_eq!(table.get_or_intern_default("frog"), frog);
+        // • Uppercase strings don't parse, so the default is interned.
+        let fallback: Symbol<Lowercase> = table.get_or_intern_default("FROG");
+        assert_eq!(fallback.to_string(), "");
+        assert!(!table.has_interned::<Lowercase, _>("FROG"));
+    }
+
+    #[test]
+    fn contains_issued_ids() {
+        let mut table = SymbolTable::new(InternerFlavor::Buffer);
+        let words = ["toad", "frog", "newt"];
+        let syms: Vec<Symbol<String>> = words.iter().map(|w| table.intern_str(w)).collect();
+        let id = |raw: u64| SerialU64::<()>::try_from(raw).unwrap();
+        for raw in 1..=table.len() as u64 {
+            assert!(table.contains_id(id(raw)));
+        }
+        assert!(!table.contains_id(id(0)));
+        assert!(!table.contains_id(id(table.len() as u64 + 1)));
+        assert!(!table.contains_id(id(u64::MAX - 1)));
+        assert!(table.remove(&syms[1]));
+        assert!(!table.contains_id(syms[1].erase_type()));
+        assert!(table.contains_id(syms[2].erase_type()));
+    }
+
+    #[test]
+    fn intern_under_runtime_types() {
+        let mut table = SymbolTable::new(InternerFlavor::Radix);
+        let address = TypeId::of::<Address>();
+        let username = TypeId::of::<Username>();
+        let first = table.intern_dyn("bob".to_owned(), address);
+        let second = table.intern_dyn("bob".to_owned(), username);
+        assert_eq!(first, second);
+        assert_eq!(table.len(), 1);
+        assert_eq!(table.resolve_dyn(first).as_deref(), Some("bob"));
+        assert_eq!(table.types_for("bob").len(), 2);
+        assert!(table.has_interned::<Address, _>("bob"));
+        assert!(!table.has_interned::<String, _>("bob"));
+        let home: Symbol<Address> = table.symbol_from_raw(first.get()).unwrap();
+        assert_eq!(home.erase_type(), first);
+        assert!(table.resolve_dyn(SerialU64::try_from(7).unwrap()).is_none());
+    }
+
+    #[test]
+    fn invariants_hold() {
+        for flavor in [
+            InternerFlavor::Array,
+            InternerFlavor::Radix,
+            InternerFlavor::Suffix,
+            InternerFlavor::Buffer,
+            InternerFlavor::FxHash,
+            InternerFlavor::Sorted,
+            InternerFlavor::SuffixIndex,
+        ] {
+            let mut table = SymbolTable::new(flavor);
+            let toad: Symbol<String> = table.intern_str("toad");
+            let _: Symbol<String> = table.intern_str("toadstool");
+            let _: Symbol<Address> = table.intern_str("toad");
+            let _: Symbol<String> = table.intern_str("");
+            assert!(table.remove(&toad));
+            assert_eq!(table.check_invariants(), Ok(()));
+        }
+        // • This interner hands out the sentinel id to a real string.
+        let table = SymbolTable::with_interner(ListInterner {
+            values: vec!["toad".to_owned()],
+            first_id: 0,
+            ..ListInterner::default()
+        });
+        let err = table.check_invariants().unwrap_err();
+        assert!(err.contains("sentinel"), "{}", err);
+    }
+
+    #[test]
+    fn suffix_search() {
+        for flavor in [InternerFlavor::Array, InternerFlavor::SuffixIndex] {
+            let mut table = SymbolTable::new(flavor);
+            let toad: Symbol<String> = table.intern_str("toad");
+            let bread: Symbol<String> = table.intern_str("bread");
+            let frog: Symbol<String> = table.intern_str("frog");
+            let _: Symbol<Address> = table.intern_str("road");
+            let found: Vec<Symbol<String>> = table.with_suffix("ad");
+            assert_eq!(found, [toad, bread]);
+            assert!(!found.contains(&frog));
+            assert_eq!(table.with_suffix::<String>("").len(), 3);
+            assert!(table.with_suffix::<String>("toads").is_empty());
+        }
+    }
+
+    #[test]
+    fn zero_capacity_bounded_holds_one_string() {
+        let mut table = SymbolTable::new(InternerFlavor::Bounded(0));
+        let toad: Symbol<String> = table.intern_str("toad");
+        assert_eq!(table.resolve(&toad), Ok("toad".to_owned()));
+        let frog: Symbol<String> = table.intern_str("frog");
+        assert_eq!(table.len(), 1);
+        assert_eq!(table.resolve(&frog), Ok("frog".to_owned()));
+    }
+
+    #[test]
+    fn intern_str_hits_refresh_bounded_strings() {
+        let mut table = SymbolTable::new(InternerFlavor::Bounded(2));
+        let a: Symbol<String> = table.intern_str("a");
+        let _: Symbol<String> = table.intern_str("b");
+        assert_eq!(table.intern_str::<String>("a"), a);
+        let _: Symbol<String> = table.intern_str("c");
+        assert_eq!(table.resolve(&a), Ok("a".to_owned()));
+        assert!(!table.has_interned::<String, _>("b"));
+    }
 }
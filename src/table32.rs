@@ -0,0 +1,167 @@
+use core::any::TypeId;
+
+use typed_ids::SerialU64;
+
+use super::{InternErr, Internable, InternerFlavor, ResolutionErr, Symbol32, SymbolTable};
+use crate::errors::parse_resolved;
+
+/// A [SymbolTable32] is a [SymbolTable] which issues 32-bit [Symbol32]s
+/// instead of [Symbol]s, for tables which will never hold more than about
+/// four billion strings. Each [Symbol32] is only four bytes wide.
+/// Once the table runs out of 32-bit ids, interning a new string fails with
+/// [InternErr::IdSpaceExhausted], rather than wrapping around.
+pub struct SymbolTable32 {
+    table:  SymbolTable,
+    // The largest id the table may issue.
+    max_id: u32,
+}
+
+impl SymbolTable32 {
+    pub fn new(flavor: InternerFlavor) -> Self {
+        Self::with_max_id(flavor, u32::MAX)
+    }
+
+    /// [with_max_id] constructs a table which issues no id greater than
+    /// `max_id`, so it runs out of ids sooner than a table from [new].
+    pub fn with_max_id(flavor: InternerFlavor, max_id: u32) -> Self {
+        Self {
+            table: SymbolTable::new(flavor),
+            max_id,
+        }
+    }
+
+    /// [intern] is like [SymbolTable::intern], but fails if the item's string
+    /// is new to the table and every id up to the table's maximum is taken.
+    /// A failed intern leaves the table unchanged, as long as the interner
+    /// reports its [Interner::next_id], as every provided interner does.
+    /// Otherwise, the string is interned and then removed, which may leave a
+    /// tombstone behind.
+    pub fn intern<T: Internable + 'static>(&mut self, item: &T) -> Result<Symbol32<T>, InternErr> {
+        // • A new string will be issued the next id, so check it is in
+        //   range before the interner is touched.
+        let next_id = {
+            let interner = self.table.interner();
+            let is_stored = interner.get_interned_any(item.as_ref()).is_some();
+            interner.next_id().filter(|_| !is_stored)
+        };
+        if next_id.is_some_and(|id| id > u64::from(self.max_id)) {
+            return Err(InternErr::IdSpaceExhausted);
+        }
+        let (sym, is_new) = self.table.intern_with_status(item);
+        match u32::try_from(sym.raw()) {
+            Ok(id) if id <= self.max_id => Ok(Symbol32::new(id)),
+            // • The interner couldn't predict the id, and it is out of
+            //   range, so take the string back out. Only a new string can
+            //   land out of range, since every string already stored was
+            //   given an id in range.
+            _ => {
+                if is_new {
+                    self.table.remove(&sym);
+                }
+                Err(InternErr::IdSpaceExhausted)
+            }
+        }
+    }
+
+    /// [resolve] returns the object that was originally stored in the table.
+    /// It fails if the id is unknown to the table, or the string can't be
+    /// parsed back into a `T`.
+    pub fn resolve<T: Internable + 'static>(
+        &self,
+        sym: &Symbol32<T>,
+    ) -> Result<T, ResolutionErr<T>> {
+        let id =
+            SerialU64::try_from(u64::from(sym.raw())).map_err(|_| ResolutionErr::UnknownIdErr)?;
+        let interner = self.table.interner();
+        let resolution = interner
+            .resolve_ref(id)
+            .ok_or(ResolutionErr::UnknownIdErr)?;
+        parse_resolved(&resolution)
+    }
+
+    pub fn get_interned<T: Internable + 'static, S: AsRef<str>>(
+        &self,
+        val: S,
+    ) -> Option<Symbol32<T>> {
+        let id = self
+            .table
+            .interner()
+            .get_interned_str(val.as_ref(), TypeId::of::<T>())?;
+        u32::try_from(id.get()).ok().map(Symbol32::new)
+    }
+
+    pub fn has_interned<T: Internable + 'static, S: AsRef<str>>(&self, val: S) -> bool {
+        self.get_interned::<T, S>(val).is_some()
+    }
+
+    /// [len] returns the number of distinct strings stored in the table.
+    pub fn len(&self) -> usize {
+        self.table.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.table.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem::size_of;
+
+    use super::SymbolTable32;
+    use crate::{InternErr, InternerFlavor, Symbol, Symbol32};
+
+    #[test]
+    fn symbols_are_compact() {
+        assert_eq!(size_of::<Symbol32<String>>(), 4);
+        assert!(size_of::<Symbol32<String>>() < size_of::<Symbol<String>>());
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut table = SymbolTable32::new(InternerFlavor::Radix);
+        let toad = table.intern(&"toad".to_owned()).unwrap();
+        let frog = table.intern(&"frog".to_owned()).unwrap();
+        assert_eq!(table.intern(&"toad".to_owned()), Ok(toad));
+        assert_ne!(toad, frog);
+        assert_eq!(table.resolve(&toad), Ok("toad".to_owned()));
+        assert_eq!(table.get_interned::<String, _>("frog"), Some(frog));
+        assert!(!table.has_interned::<Box<str>, _>("frog"));
+        assert_eq!(table.len(), 2);
+    }
+
+    #[test]
+    fn overflow_is_an_error() {
+        let mut table = SymbolTable32::with_max_id(InternerFlavor::Array, 2);
+        let frog = table.intern(&"frog".to_owned()).unwrap();
+        let toad = table.intern(&"toad".to_owned()).unwrap();
+        assert_eq!(
+            table.intern(&"newt".to_owned()),
+            Err(InternErr::IdSpaceExhausted)
+        );
+        // • The failed intern left nothing behind, and strings which
+        //   are already stored can still be interned.
+        assert_eq!(table.len(), 2);
+        assert!(!table.has_interned::<String, _>("newt"));
+        assert_eq!(table.intern(&"frog".to_owned()), Ok(frog));
+        assert_eq!(table.intern(&"toad".to_owned()), Ok(toad));
+        let boxed: Box<str> = "toad".into();
+        assert_eq!(table.intern(&boxed).map(|sym| sym.raw()), Ok(toad.raw()));
+    }
+
+    #[test]
+    fn overflow_leaves_the_table_unchanged() {
+        for flavor in [InternerFlavor::Array, InternerFlavor::Bounded(2)] {
+            let mut table = SymbolTable32::with_max_id(flavor, 2);
+            let frog = table.intern(&"frog".to_owned()).unwrap();
+            let toad = table.intern(&"toad".to_owned()).unwrap();
+            let before = table.table.stats();
+            assert!(table.intern(&"newt".to_owned()).is_err());
+            // • Nothing was evicted or left behind as a tombstone.
+            assert_eq!(table.table.stats(), before);
+            assert_eq!(table.table.interner().next_id(), Some(3));
+            assert_eq!(table.resolve(&frog), Ok("frog".to_owned()));
+            assert_eq!(table.resolve(&toad), Ok("toad".to_owned()));
+        }
+    }
+}
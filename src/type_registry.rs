@@ -0,0 +1,27 @@
+use std::any::TypeId;
+use std::sync::Mutex;
+
+// TypeIds are not stable across builds, so they can't be written to disk
+// directly. Instead, each TypeId is assigned an opaque index the first time
+// it is serialized, and that index is mapped back to the TypeId on load.
+// These indices are only meaningful within the process that assigned them.
+static REGISTRY: Mutex<Vec<TypeId>> = Mutex::new(Vec::new());
+
+/// [index_of] returns the opaque index standing in for this [TypeId],
+/// assigning a new one if the type has never been serialized before.
+pub(crate) fn index_of(typ: TypeId) -> u64 {
+    let mut registry = REGISTRY.lock().unwrap();
+    let index = match registry.iter().position(|known| *known == typ) {
+        Some(index) => index,
+        None => {
+            registry.push(typ);
+            registry.len() - 1
+        }
+    };
+    index as u64
+}
+
+/// [type_at] recovers the [TypeId] assigned to this index, if any.
+pub(crate) fn type_at(index: u64) -> Option<TypeId> {
+    REGISTRY.lock().unwrap().get(index as usize).cloned()
+}
@@ -0,0 +1,79 @@
+//! Counts heap allocations to check the interners' memory claims. The
+//! counting allocator replaces the global allocator for this whole test
+//! binary, which is why these tests live apart from the unit tests.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::any::TypeId;
+use std::cell::Cell;
+
+use symboltable::{ArrayInterner, BufferInterner, Interner, InternerFlavor, Symbol, SymbolTable};
+
+// Counts the heap blocks held by each thread, so the tests
+// running in parallel don't disturb each other's counts.
+struct CountingAlloc;
+
+thread_local! {
+    static LIVE_BLOCKS: Cell<isize> = const { Cell::new(0) };
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        LIVE_BLOCKS.with(|count| count.set(count.get() + 1));
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        LIVE_BLOCKS.with(|count| count.set(count.get() - 1));
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAlloc = CountingAlloc;
+
+// Returns the number of heap blocks left behind by interning
+// many small strings, including the interner itself.
+fn blocks_held<I: Interner>(new: impl FnOnce() -> I) -> (isize, I) {
+    let typ = TypeId::of::<String>();
+    let before = LIVE_BLOCKS.with(Cell::get);
+    let mut interner = new();
+    for i in 0..1000 {
+        interner.intern(format!("s{}", i), typ).unwrap();
+    }
+    let after = LIVE_BLOCKS.with(Cell::get);
+    (after - before, interner)
+}
+
+// Returns the number of allocations made while running `f`.
+fn allocations(f: &mut dyn FnMut()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+#[test]
+fn buffer_holds_fewer_blocks_than_array() {
+    let (array_blocks, array) = blocks_held(ArrayInterner::new);
+    let (buffer_blocks, buffer) = blocks_held(BufferInterner::new);
+    assert_eq!(array.strings(), buffer.strings());
+    assert!(
+        buffer_blocks < array_blocks,
+        "buffer held {} blocks, array held {}",
+        buffer_blocks,
+        array_blocks
+    );
+}
+
+#[test]
+fn intern_str_hits_do_not_allocate() {
+    let mut table = SymbolTable::new(InternerFlavor::Buffer);
+    let toad: Symbol<String> = table.intern_str("toad");
+    // • Issuing any Symbol allocates its handle on the table, so
+    //   a hit should allocate nothing beyond that.
+    let per_symbol = allocations(&mut || drop(Symbol::<String>::null(&table)));
+    let mut again = None;
+    let per_hit = allocations(&mut || again = Some(table.intern_str::<String>("toad")));
+    assert_eq!(again, Some(toad));
+    assert_eq!(per_hit, per_symbol);
+}